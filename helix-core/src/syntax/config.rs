@@ -271,6 +271,7 @@ pub enum LanguageServerFeature {
     RenameSymbol,
     InlayHints,
     DocumentColors,
+    DocumentLinks,
 }
 
 impl Display for LanguageServerFeature {
@@ -295,6 +296,7 @@ impl Display for LanguageServerFeature {
             RenameSymbol => "rename-symbol",
             InlayHints => "inlay-hints",
             DocumentColors => "document-colors",
+            DocumentLinks => "document-links",
         };
         write!(f, "{feature}",)
     }
@@ -130,6 +130,11 @@ pub struct StartFoldPoint {
     /// The first line of block.
     pub line: usize,
 
+    /// Text yielded by the folded char/grapheme iterators in place of the collapsed block, e.g.
+    /// `"{ … }"` or a function signature. `None` means those iterators yield nothing for the fold.
+    /// The fold decoration in the UI draws its own generic label whether or not this is set.
+    pub text: Option<Box<str>>,
+
     /// An index of `EndFoldPoint` relating to the same fold.
     link: usize,
     /// An index of `StartFoldPoint` relating to the super fold.
@@ -146,6 +151,12 @@ impl StartFoldPoint {
         self.super_link.is_none()
     }
 
+    /// Attaches placeholder text to be displayed in place of the collapsed block.
+    pub fn with_text(mut self, text: impl Into<Box<str>>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
     fn from(text: RopeSlice, object: FoldObject, header: usize, target: usize) -> Self {
         let mut result = Self {
             object,
@@ -154,6 +165,7 @@ impl StartFoldPoint {
             byte: 0,
             char: 0,
             line: 0,
+            text: None,
             link: 0,
             super_link: None,
         };
@@ -271,6 +283,12 @@ impl<'a> Fold<'a> {
         self.start.header
     }
 
+    /// Returns the custom placeholder text to display in place of the collapsed block, if any
+    /// was set via [`StartFoldPoint::with_text`].
+    pub fn text(self) -> Option<&'a str> {
+        self.start.text.as_deref()
+    }
+
     pub fn is_superest(self) -> bool {
         self.start.super_link.is_none()
     }
@@ -519,6 +537,12 @@ impl FoldContainer {
         &self.start_points[start..end]
     }
 
+    /// Finds the innermost fold containing `idx`, binary-searching `end_points` (sorted by
+    /// target end) in `O(log n)` rather than scanning every fold. When the fold found this way
+    /// doesn't actually contain `idx` (its target ends past `idx`, but it started after it too),
+    /// walks up the `super_link` chain set by [`set_super_links`](Self::set_super_links) — so a
+    /// fold nested inside another (e.g. a method folded inside an already-folded class) is found
+    /// the same as a top-level one.
     pub fn fold_containing(
         &self,
         idx: usize,
@@ -540,6 +564,9 @@ impl FoldContainer {
         Some(fold)
     }
 
+    /// Like [`fold_containing`](Self::fold_containing), but keeps walking up nested folds to
+    /// return the outermost one, e.g. the whole collapsed class rather than the method collapsed
+    /// inside it.
     pub fn superest_fold_containing(
         &self,
         idx: usize,
@@ -554,6 +581,82 @@ impl FoldContainer {
     }
 }
 
+/// A read-only view of a [`FoldContainer`] used by [`ropex`](super::text_folding::ropex) to seek
+/// to and step over folds while iterating a [`RopeSlice`] in char/byte/line units.
+///
+/// `FoldContainer` already keeps `start_points`/`end_points` sorted (see
+/// [`sort_start_points`](FoldContainer::sort_start_points)/
+/// [`sort_end_points`](FoldContainer::sort_end_points)) and resolves nesting through
+/// `super_link` chains (see [`set_super_links`](FoldContainer::set_super_links)), so
+/// [`consume_next`](Self::consume_next)/[`consume_prev`](Self::consume_prev) reuse that existing
+/// sorted storage with a binary search rather than building a second, separate interval tree:
+/// each call is `O(log n)` regardless of how many folds are in the container. `None` means there
+/// is no active fold container (e.g. folding is disabled for the view), in which case every query
+/// below reports "nothing folded".
+#[derive(Debug, Clone, Copy)]
+pub struct FoldAnnotations<'a> {
+    container: Option<&'a FoldContainer>,
+}
+
+impl<'a> FoldAnnotations<'a> {
+    pub fn new(container: Option<&'a FoldContainer>) -> Self {
+        Self { container }
+    }
+
+    /// No-op: unlike a stateful cursor, every `consume_next`/`consume_prev` call below
+    /// independently binary-searches the container's sorted fold points, so there's no running
+    /// position to seed ahead of time.
+    pub(crate) fn reset_pos(&self, _idx: usize, _key: impl Fn(Fold) -> usize) {}
+
+    /// Returns the fold starting exactly at `idx` in `key`'s unit, if any, via a binary search
+    /// over `start_points` (kept sorted by [`sort_start_points`](FoldContainer::sort_start_points)).
+    pub(crate) fn consume_next(
+        &self,
+        idx: usize,
+        key: impl Fn(Fold<'a>) -> usize,
+    ) -> Option<Fold<'a>> {
+        let container = self.container?;
+        let i = container
+            .start_points
+            .partition_point(|sfp| key(sfp.fold(container)) < idx);
+        let fold = container.start_points.get(i)?.fold(container);
+        (key(fold) == idx).then_some(fold)
+    }
+
+    /// Returns the fold ending exactly at `idx` in `key`'s unit, if any, via a binary search over
+    /// `end_points` (kept sorted by [`sort_end_points`](FoldContainer::sort_end_points)).
+    pub(crate) fn consume_prev(
+        &self,
+        idx: usize,
+        key: impl Fn(Fold<'a>) -> usize,
+    ) -> Option<Fold<'a>> {
+        let container = self.container?;
+        let i = container
+            .end_points
+            .partition_point(|efp| key(efp.fold(container)) < idx);
+        let fold = container.end_points.get(i)?.fold(container);
+        (key(fold) == idx).then_some(fold)
+    }
+
+    /// Counts the lines inside `range` that are hidden behind a fold's placeholder, i.e. every
+    /// line of a fold's block except its own (visible) first line. Unlike `consume_next`/
+    /// `consume_prev`, this walks `range` line by line, so it's `O(range length · log n)` rather
+    /// than `O(log n)` — fine for the gutter/gitgraph-sized ranges this is used for.
+    pub fn folded_lines_between(&self, range: &ops::RangeInclusive<usize>) -> usize {
+        let Some(container) = self.container else {
+            return 0;
+        };
+
+        (*range.start()..=*range.end())
+            .filter(|&line| {
+                container
+                    .superest_fold_containing(line, |fold| fold.start.line..=fold.end.line)
+                    .is_some_and(|fold| line > fold.start.line)
+            })
+            .count()
+    }
+}
+
 impl FoldContainer {
     fn sort_start_points(&mut self) {
         self.start_points.sort_by(|sfp1, sfp2| {
@@ -1,11 +1,13 @@
 //! Implements fold-oriented methods for `RopeSlice`.
 
+use std::iter::FusedIterator;
+
 use crate::ropey::iter::{Chars, Lines};
 use crate::RopeSlice;
 
 use helix_stdx::rope::{RopeGraphemes, RopeSliceExt};
 
-use super::FoldAnnotations;
+use super::{Fold, FoldAnnotations};
 
 pub trait RopeSliceFoldExt<'a> {
     /// Similar to the native `chars` method.
@@ -314,6 +316,17 @@ macro_rules! FoldedWrapper {
                 self.inner.next()
             }
         }
+
+        impl<'a> DoubleEndedIterator for $Name<'a> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.prev()
+            }
+        }
+
+        // `next_impl`/`prev_impl` return `None` as soon as `idx` reaches either end of the
+        // slice and leave it there, so once this iterator is exhausted in one direction it
+        // stays exhausted.
+        impl<'a> FusedIterator for $Name<'a> {}
     };
 }
 
@@ -321,13 +334,19 @@ FoldedWrapper!(FoldedChars, Chars);
 FoldedWrapper!(FoldedGraphemes, RopeGraphemes);
 FoldedWrapper!(FoldedLines, Lines);
 
-struct FoldedTextItems<'a, Items> {
+// When a fold carries a `text` placeholder (see `StartFoldPoint::with_text`), `next_impl`/
+// `prev_impl` drain it item-by-item (in `pending`) before jumping to the other side of the fold,
+// so callers see the placeholder's chars/graphemes instead of nothing. Folds without a placeholder
+// are skipped exactly as before. Placeholder items are anchored at the fold's own start/end
+// position for the purposes of `last_idx`, since they don't correspond to real positions in `slice`.
+struct FoldedTextItems<'a, Items: TextItems<'a>> {
     items: Items,
     slice: RopeSlice<'a>,
     annotations: &'a FoldAnnotations<'a>,
     idx: usize,
     last_idx: Option<usize>,
     is_reversed: bool,
+    pending: Vec<Items::Item>,
 }
 
 impl<'a, Items: TextItems<'a>> FoldedTextItems<'a, Items> {
@@ -340,6 +359,7 @@ impl<'a, Items: TextItems<'a>> FoldedTextItems<'a, Items> {
             idx,
             last_idx: None,
             is_reversed: false,
+            pending: Vec::new(),
         }
     }
 
@@ -353,16 +373,28 @@ impl<'a, Items: TextItems<'a>> FoldedTextItems<'a, Items> {
     }
 
     fn prev_impl(&mut self) -> Option<Items::Item> {
+        if let Some(item) = self.pending.pop() {
+            return Some(item);
+        }
+
         if self.idx == 0 {
             self.last_idx = None;
             return None;
         }
 
         self.idx -= 1;
-        if let Some(position) = Items::consume_prev(self.annotations, self.idx) {
-            self.idx = position;
+        if let Some(fold) = Items::consume_prev(self.annotations, self.idx) {
+            self.idx = Items::fold_start(fold);
             self.items = Items::at(self.slice, self.idx);
 
+            if let Some(text) = fold.text() {
+                self.last_idx = Some(Items::fold_end(fold));
+                self.pending = Items::placeholder_items(text);
+                if let Some(item) = self.pending.pop() {
+                    return Some(item);
+                }
+            }
+
             return self.prev_impl();
         }
 
@@ -376,15 +408,27 @@ impl<'a, Items: TextItems<'a>> FoldedTextItems<'a, Items> {
     }
 
     fn next_impl(&mut self) -> Option<Items::Item> {
+        if let Some(item) = pop_front(&mut self.pending) {
+            return Some(item);
+        }
+
         if self.idx == Items::len(self.slice) {
             self.last_idx = None;
             return None;
         }
 
-        if let Some(position) = Items::consume_next(self.annotations, self.idx) {
-            self.idx = position + 1;
+        if let Some(fold) = Items::consume_next(self.annotations, self.idx) {
+            self.idx = Items::fold_end(fold) + 1;
             self.items = Items::at(self.slice, self.idx);
 
+            if let Some(text) = fold.text() {
+                self.last_idx = Some(Items::fold_start(fold));
+                self.pending = Items::placeholder_items(text);
+                if let Some(item) = pop_front(&mut self.pending) {
+                    return Some(item);
+                }
+            }
+
             return self.next_impl();
         }
 
@@ -400,6 +444,17 @@ impl<'a, Items: TextItems<'a>> FoldedTextItems<'a, Items> {
     }
 }
 
+/// Pops the first queued placeholder item, preserving the order it was collected in.
+/// `pending` is drained back-to-front by `prev_impl` and front-to-back by `next_impl`, so the
+/// forward direction removes from the front instead of reusing `Vec::pop`.
+fn pop_front<T>(pending: &mut Vec<T>) -> Option<T> {
+    if pending.is_empty() {
+        None
+    } else {
+        Some(pending.remove(0))
+    }
+}
+
 impl<'a, Items: TextItems<'a>> Iterator for FoldedTextItems<'a, Items> {
     type Item = Items::Item;
 
@@ -419,8 +474,16 @@ trait TextItems<'a>: Iterator {
     fn len(slice: RopeSlice) -> usize;
     fn prev_impl(&mut self) -> Option<Self::Item>;
     fn next_impl(&mut self) -> Option<Self::Item>;
-    fn consume_prev(annotations: &FoldAnnotations, idx: usize) -> Option<usize>;
-    fn consume_next(annotations: &FoldAnnotations, idx: usize) -> Option<usize>;
+    fn consume_prev(annotations: &'a FoldAnnotations<'a>, idx: usize) -> Option<Fold<'a>>;
+    fn consume_next(annotations: &'a FoldAnnotations<'a>, idx: usize) -> Option<Fold<'a>>;
+    /// The fold's own start position, in this `TextItems`'s unit (char/byte/line).
+    fn fold_start(fold: Fold<'a>) -> usize;
+    /// The fold's own end position, in this `TextItems`'s unit (char/byte/line).
+    fn fold_end(fold: Fold<'a>) -> usize;
+    /// Converts a fold's placeholder text into the items that should be yielded in place of the
+    /// folded region. Returns an empty `Vec` when this `TextItems` has no meaningful placeholder
+    /// representation (e.g. lines, where a single-line placeholder can't be split further).
+    fn placeholder_items(text: &'a str) -> Vec<Self::Item>;
 }
 
 impl<'a> TextItems<'a> for Chars<'a> {
@@ -444,16 +507,24 @@ impl<'a> TextItems<'a> for Chars<'a> {
         self.next()
     }
 
-    fn consume_prev(annotations: &FoldAnnotations, char_idx: usize) -> Option<usize> {
-        annotations
-            .consume_prev(char_idx, |fold| fold.end.char)
-            .map(|fold| fold.start.char)
+    fn consume_prev(annotations: &'a FoldAnnotations<'a>, char_idx: usize) -> Option<Fold<'a>> {
+        annotations.consume_prev(char_idx, |fold| fold.end.char)
     }
 
-    fn consume_next(annotations: &FoldAnnotations, char_idx: usize) -> Option<usize> {
-        annotations
-            .consume_next(char_idx, |fold| fold.start.char)
-            .map(|fold| fold.end.char)
+    fn consume_next(annotations: &'a FoldAnnotations<'a>, char_idx: usize) -> Option<Fold<'a>> {
+        annotations.consume_next(char_idx, |fold| fold.start.char)
+    }
+
+    fn fold_start(fold: Fold<'a>) -> usize {
+        fold.start.char
+    }
+
+    fn fold_end(fold: Fold<'a>) -> usize {
+        fold.end.char
+    }
+
+    fn placeholder_items(text: &'a str) -> Vec<Self::Item> {
+        text.chars().collect()
     }
 }
 
@@ -478,16 +549,24 @@ impl<'a> TextItems<'a> for RopeGraphemes<'a> {
         self.next()
     }
 
-    fn consume_prev(annotations: &FoldAnnotations, byte_idx: usize) -> Option<usize> {
-        annotations
-            .consume_prev(byte_idx, |fold| fold.end.byte)
-            .map(|fold| fold.start.byte)
+    fn consume_prev(annotations: &'a FoldAnnotations<'a>, byte_idx: usize) -> Option<Fold<'a>> {
+        annotations.consume_prev(byte_idx, |fold| fold.end.byte)
+    }
+
+    fn consume_next(annotations: &'a FoldAnnotations<'a>, byte_idx: usize) -> Option<Fold<'a>> {
+        annotations.consume_next(byte_idx, |fold| fold.start.byte)
+    }
+
+    fn fold_start(fold: Fold<'a>) -> usize {
+        fold.start.byte
     }
 
-    fn consume_next(annotations: &FoldAnnotations, byte_idx: usize) -> Option<usize> {
-        annotations
-            .consume_next(byte_idx, |fold| fold.start.byte)
-            .map(|fold| fold.end.byte)
+    fn fold_end(fold: Fold<'a>) -> usize {
+        fold.end.byte
+    }
+
+    fn placeholder_items(text: &'a str) -> Vec<Self::Item> {
+        RopeSlice::from(text).graphemes().collect()
     }
 }
 
@@ -512,15 +591,25 @@ impl<'a> TextItems<'a> for Lines<'a> {
         self.next()
     }
 
-    fn consume_prev(annotations: &FoldAnnotations, line_idx: usize) -> Option<usize> {
-        annotations
-            .consume_prev(line_idx, |fold| fold.end.line)
-            .map(|fold| fold.start.line)
+    fn consume_prev(annotations: &'a FoldAnnotations<'a>, line_idx: usize) -> Option<Fold<'a>> {
+        annotations.consume_prev(line_idx, |fold| fold.end.line)
+    }
+
+    fn consume_next(annotations: &'a FoldAnnotations<'a>, line_idx: usize) -> Option<Fold<'a>> {
+        annotations.consume_next(line_idx, |fold| fold.start.line)
+    }
+
+    fn fold_start(fold: Fold<'a>) -> usize {
+        fold.start.line
+    }
+
+    fn fold_end(fold: Fold<'a>) -> usize {
+        fold.end.line
     }
 
-    fn consume_next(annotations: &FoldAnnotations, line_idx: usize) -> Option<usize> {
-        annotations
-            .consume_next(line_idx, |fold| fold.start.line)
-            .map(|fold| fold.end.line)
+    // A fold's placeholder is a single inline label, not separate lines, so `folded_lines`
+    // keeps skipping straight across the fold rather than inserting synthetic lines.
+    fn placeholder_items(_text: &'a str) -> Vec<Self::Item> {
+        Vec::new()
     }
 }
@@ -23,28 +23,46 @@ use crate::{graphemes::prev_grapheme_boundary, transaction::UpdatePosition, Tran
 use super::FoldContainer;
 
 impl FoldContainer {
+    /// Remaps every fold's `header`/`target`/block positions through `transaction`, the same
+    /// way [`Selection`](crate::Selection) ranges are mapped, instead of rescanning the whole
+    /// document. Folds disturbed by the edit (header mixed with outer text, target boundary
+    /// removed, etc. — see [`disturbed_folds`](Self::disturbed_folds)) are dropped; everything
+    /// else keeps its relative position, whether that means staying put, shifting by the net
+    /// delta, or moving past an insertion at its boundary. Returns the start indices (as they
+    /// stood before this call) of every fold that was dropped, so callers can drop the matching
+    /// gutter markers instead of redrawing all of them.
+    ///
+    /// This takes the full `Transaction` rather than a bare `ChangeSet`: dropping disturbed folds
+    /// needs `old_text` around each change, not just its length.
     pub fn update_by_transaction(
         &mut self,
         new_text: RopeSlice,
         old_text: RopeSlice,
         transaction: &Transaction,
-    ) {
+    ) -> Vec<usize> {
+        let original_len = self.start_points.len();
+
         let disturbed = self.disturbed_folds(old_text, transaction);
         let mut sort = !disturbed.is_empty();
 
-        self.delete(disturbed);
+        self.delete(disturbed.clone());
 
         self.update(new_text, transaction.changes());
 
         let removables = self.normalize(new_text);
         sort |= !removables.is_empty();
 
-        self.delete(removables);
+        self.delete(removables.clone());
 
         if sort {
             self.sort_end_points();
             self.set_super_links();
         }
+
+        let mut removed = restore_original_indices(&disturbed, &removables, original_len);
+        removed.extend(disturbed);
+        removed.sort_unstable();
+        removed
     }
 
     /// Returns the start indices of folds that have been disturbed when the transaction is applied.
@@ -287,3 +305,27 @@ impl<'a> UpdatePosition<RopeSlice<'a>> for ComponentUpdater<'a> {
         };
     }
 }
+
+/// `removables` are indices into the fold array *after* `removed_first` (sorted, deduped) has
+/// already been deleted from it. Translates each one back to the index it had before that
+/// deletion, so a caller tracking fold ids from before `removed_first` was applied can still
+/// recognize it.
+fn restore_original_indices(
+    removed_first: &[usize],
+    removables: &[usize],
+    original_len: usize,
+) -> Vec<usize> {
+    let mut removed_iter = removed_first.iter().peekable();
+    let survivors: Vec<usize> = (0..original_len)
+        .filter(|i| {
+            if removed_iter.peek() == Some(&i) {
+                removed_iter.next();
+                false
+            } else {
+                true
+            }
+        })
+        .collect();
+
+    removables.iter().map(|&idx| survivors[idx]).collect()
+}
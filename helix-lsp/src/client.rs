@@ -58,6 +58,14 @@ pub struct Client {
 }
 
 impl Client {
+    /// Reuses this client for `doc_path` if possible, instead of spawning a new server.
+    ///
+    /// This is the "user opens a file outside all known roots" half of multi-root support: if
+    /// `doc_path`'s root isn't already in [`Client::workspace_folders`], this adds it (via
+    /// [`Client::add_workspace_folder`], which sends `workspace/didChangeWorkspaceFolders` when
+    /// the server's `change_notifications` capability allows it) and reuses this server rather
+    /// than starting a second instance for the new root. See [`Client::remove_workspace_folder`]
+    /// for the reverse (explicit) direction.
     pub fn try_add_doc(
         self: &Arc<Self>,
         root_markers: &[String],
@@ -166,6 +174,37 @@ impl Client {
         tokio::spawn(self.did_change_workspace(vec![workspace_for_uri(root_uri)], Vec::new()));
     }
 
+    /// Explicitly drop a workspace folder from this server's tracked roots, e.g. because the
+    /// user closed the last document belonging to it. Unlike [`Client::add_workspace_folder`]
+    /// (see [`Client::try_add_doc`] for the auto-add-on-open-file side of that) this is never
+    /// inferred automatically: callers must know the folder is no longer needed.
+    ///
+    /// This only covers the removal side; advertising `workspaceFolders` support and responding
+    /// to `workspace/workspaceFolders` requests (see `MethodCall::WorkspaceFolders` in
+    /// `helix-term`) are handled elsewhere.
+    pub fn remove_workspace_folder(&self, root_uri: Option<lsp::Url>) {
+        let Some(root_uri) = root_uri else {
+            return;
+        };
+
+        self.workspace_folders
+            .lock()
+            .retain(|workspace| workspace.uri != root_uri);
+
+        let change_notifications_disabled = self
+            .capabilities
+            .get()
+            .and_then(|capabilities| capabilities.workspace.as_ref())
+            .and_then(|cap| cap.workspace_folders.as_ref())
+            .is_some_and(|cap| cap.change_notifications == Some(OneOf::Left(false)));
+
+        if change_notifications_disabled {
+            // server specifically opted out of DidWorkspaceChange notifications
+            return;
+        }
+        tokio::spawn(self.did_change_workspace(Vec::new(), vec![workspace_for_uri(root_uri)]));
+    }
+
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
     pub fn start(
@@ -1053,6 +1092,47 @@ impl Client {
         Some(self.call::<lsp::request::DocumentHighlightRequest>(params))
     }
 
+    pub fn text_document_document_link(
+        &self,
+        text_document: lsp::TextDocumentIdentifier,
+        work_done_token: Option<lsp::ProgressToken>,
+    ) -> Option<impl Future<Output = Result<Option<Vec<lsp::DocumentLink>>>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        // Return early if the server does not support document links.
+        capabilities.document_link_provider.as_ref()?;
+
+        let params = lsp::DocumentLinkParams {
+            text_document,
+            work_done_progress_params: lsp::WorkDoneProgressParams { work_done_token },
+            partial_result_params: lsp::PartialResultParams {
+                partial_result_token: None,
+            },
+        };
+
+        Some(self.request::<lsp::request::DocumentLinkRequest>(params))
+    }
+
+    /// Resolves the `target` of a document link that was returned without one.
+    /// Only servers that advertise `resolveProvider: true` support this.
+    pub fn document_link_resolve(
+        &self,
+        document_link: lsp::DocumentLink,
+    ) -> Option<impl Future<Output = Result<lsp::DocumentLink>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        // Return early if the server does not support resolving document links.
+        match capabilities.document_link_provider {
+            Some(lsp::DocumentLinkOptions {
+                resolve_provider: Some(true),
+                ..
+            }) => (),
+            _ => return None,
+        }
+
+        Some(self.request::<lsp::request::DocumentLinkResolve>(document_link))
+    }
+
     fn goto_request<
         T: lsp::request::Request<
             Params = lsp::GotoDefinitionParams,
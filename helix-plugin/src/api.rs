@@ -1,7 +1,10 @@
 use anyhow::Result;
 use helix_view::editor::EditorEvent;
+use std::cell::Cell;
 use tokio::sync::mpsc::UnboundedSender;
 
+use crate::config::Permissions;
+
 // Esta struct representa a API que o Helix expõe aos plugins.
 // Ela será passada para os hosts WASM e Lua.
 pub struct HelixApi {
@@ -9,11 +12,41 @@ pub struct HelixApi {
     editor_event_sender: UnboundedSender<EditorEvent>,
     plugin_idx: usize, // Identificador do plugin que possui esta API
     next_request_id: Cell<u32>,
+    // Capacidades declaradas em `plugin.toml`; qualquer escopo não listado aqui é negado.
+    permissions: Permissions,
 }
 
 impl HelixApi {
-    pub fn new(editor_event_sender: UnboundedSender<EditorEvent>, plugin_idx: usize) -> Self {
-        Self { editor_event_sender, plugin_idx, next_request_id: Cell::new(0) }
+    pub fn new(
+        editor_event_sender: UnboundedSender<EditorEvent>,
+        plugin_idx: usize,
+        permissions: Permissions,
+    ) -> Self {
+        Self {
+            editor_event_sender,
+            plugin_idx,
+            next_request_id: Cell::new(0),
+            permissions,
+        }
+    }
+
+    // Verifica se o plugin declarou o escopo `scope` em `[permissions.editor_scopes]`.
+    // Nega por padrão: se o escopo não estiver na lista, retorna erro em vez de tocar o editor.
+    fn check_scope(&self, scope: &str) -> Result<()> {
+        if self.permissions.editor_scopes.iter().any(|s| s == scope) {
+            Ok(())
+        } else {
+            log::warn!(
+                "Plugin {} denied capability '{}': not declared in plugin.toml [permissions]",
+                self.plugin_idx,
+                scope
+            );
+            Err(anyhow::anyhow!(
+                "plugin {} is missing required capability '{}'",
+                self.plugin_idx,
+                scope
+            ))
+        }
     }
 
     // Exemplo de função da API: exibir uma mensagem de status.
@@ -28,6 +61,7 @@ impl HelixApi {
 
     // Registrar um comando de plugin.
     pub fn register_command(&self, command_name: String, callback_function_name: String) -> Result<()> {
+        self.check_scope("commands.register")?;
         self.editor_event_sender.send(EditorEvent::RegisterPluginCommand(
             command_name,
             callback_function_name,
@@ -37,6 +71,7 @@ impl HelixApi {
     }
 
     pub fn subscribe_to_event(&self, event_name: String, callback_function_name: String) -> Result<()> {
+        self.check_scope("events.subscribe")?;
         self.editor_event_sender.send(EditorEvent::PluginCommand(
             "subscribe_to_event".to_string(),
             vec![event_name, callback_function_name, self.plugin_idx.to_string()],
@@ -46,6 +81,7 @@ impl HelixApi {
     }
 
     pub fn get_buffer_content(&self, doc_id: u32, request_id: u32) -> Result<()> {
+        self.check_scope("buffer.read")?;
         self.editor_event_sender.send(EditorEvent::PluginCommand(
             "get_buffer_content".to_string(),
             vec![doc_id.to_string()],
@@ -55,6 +91,7 @@ impl HelixApi {
     }
 
     pub fn insert_text(&self, doc_id: u32, position: u32, text: String) -> Result<()> {
+        self.check_scope("buffer.write")?;
         self.editor_event_sender.send(EditorEvent::PluginCommand(
             "insert_text".to_string(),
             vec![doc_id.to_string(), position.to_string(), text],
@@ -64,6 +101,7 @@ impl HelixApi {
     }
 
     pub fn delete_text(&self, doc_id: u32, start: u32, end: u32) -> Result<()> {
+        self.check_scope("buffer.write")?;
         self.editor_event_sender.send(EditorEvent::PluginCommand(
             "delete_text".to_string(),
             vec![doc_id.to_string(), start.to_string(), end.to_string()],
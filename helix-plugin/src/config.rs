@@ -11,6 +11,21 @@ pub struct PluginManifest {
     pub entrypoint: PathBuf,
     #[serde(default)]
     pub activation: Activation,
+    #[serde(default)]
+    pub commands: Vec<PluginCommand>,
+    #[serde(default)]
+    pub events: Vec<PluginEvent>,
+    #[serde(default)]
+    pub permissions: Permissions,
+    /// Seconds to wait for a response to a plugin request (see `HelixApi::get_buffer_content`
+    /// and friends) before it's swept by `PluginManager::sweep_timeouts`. Defaults to 30s.
+    /// Works like the per-server `timeout` of `LanguageServerConfiguration`, which defaults to 20s.
+    #[serde(default = "default_request_timeout")]
+    pub timeout: u64,
+}
+
+pub fn default_request_timeout() -> u64 {
+    30
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -23,6 +38,46 @@ pub struct Activation {
     pub on_event: Vec<String>,
 }
 
+// Um comando que o plugin expõe ao command palette, ex: `[[commands]]`.
+#[derive(Debug, Deserialize)]
+pub struct PluginCommand {
+    pub name: String,
+    pub callback: String,
+}
+
+// Uma assinatura a um evento do editor, ex: `[[events]]`.
+#[derive(Debug, Deserialize)]
+pub struct PluginEvent {
+    pub name: String,
+    pub callback: String,
+}
+
+/// Capabilities a plugin declares in its `[permissions]` section.
+///
+/// Only `editor_scopes` is enforced: `HelixApi` refuses a scoped call (buffer read/write, command
+/// registration, event subscription) whose scope is not listed. `fs_read`, `fs_write`, `network`
+/// and `process_spawn` are parsed but not checked anywhere, and the Lua host runs with the
+/// standard `Lua::new()` libraries.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct Permissions {
+    /// Filesystem paths the plugin may read.
+    #[serde(default)]
+    pub fs_read: Vec<PathBuf>,
+    /// Filesystem paths the plugin may write.
+    #[serde(default)]
+    pub fs_write: Vec<PathBuf>,
+    /// Whether the plugin may make network requests.
+    #[serde(default)]
+    pub network: bool,
+    /// Whether the plugin may spawn subprocesses.
+    #[serde(default)]
+    pub process_spawn: bool,
+    /// Named editor-state scopes the plugin may use, e.g. `"buffer.read"`, `"buffer.write"`,
+    /// `"commands.register"`, `"events.subscribe"`.
+    #[serde(default)]
+    pub editor_scopes: Vec<String>,
+}
+
 impl PluginManifest {
     pub fn load_from(path: &PathBuf) -> anyhow::Result<Self> {
         let content = std::fs::read_to_string(path)?;
@@ -1,5 +1,7 @@
 use crate::{config::PluginManifest, host::{wasm::WasmHost, lua::LuaHost}, api::HelixApi};
 use std::path::PathBuf;
+use std::fmt;
+use std::time::{Duration, Instant};
 use walkdir::WalkDir;
 use anyhow::Result;
 use std::collections::HashMap;
@@ -11,22 +13,49 @@ pub enum PluginHost {
 
 pub struct LoadedPlugin {
     pub manifest: PluginManifest,
+    pub manifest_path: PathBuf,
     pub host: PluginHost,
 }
 
+/// A stable identifier for a loaded plugin.
+///
+/// Unlike a `Vec` index, a `PluginId` keeps pointing at the same plugin even after another
+/// plugin is unloaded, so callers (registered commands, event subscribers, pending requests)
+/// never need to be re-indexed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PluginId(u64);
+
+impl fmt::Display for PluginId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 #[derive(Debug)]
 pub struct PluginManager {
-    pub loaded_plugins: Vec<LoadedPlugin>,
+    pub loaded_plugins: HashMap<PluginId, LoadedPlugin>,
+    next_plugin_id: u64,
     api_sender: tokio::sync::mpsc::UnboundedSender<helix_view::editor::EditorEvent>,
-    // Mapeia o nome do comando para (nome da função de callback, índice do plugin)
-    registered_commands: HashMap<String, (String, usize)>,
+    // Mapeia o nome do comando para (nome da função de callback, id do plugin)
+    registered_commands: HashMap<String, (String, PluginId)>,
+    // Mapeia o nome do evento para a lista de (id do plugin, nome da função de callback)
+    event_subscribers: HashMap<String, Vec<(PluginId, String)>>,
     next_request_id: u32,
-    pending_requests: HashMap<u32, (usize, String)>, // request_id -> (plugin_idx, callback_fn_name)
+    // request_id -> (plugin_id, callback_fn_name, deadline)
+    pending_requests: HashMap<u32, (PluginId, String, Instant)>,
 }
 
 impl PluginManager {
     pub fn new(api_sender: tokio::sync::mpsc::UnboundedSender<helix_view::editor::EditorEvent>) -> Self {
-        Self { loaded_plugins: vec![], api_sender, registered_commands: HashMap::new(), next_request_id: 0, pending_requests: HashMap::new() }
+        Self {
+            loaded_plugins: HashMap::new(),
+            next_plugin_id: 0,
+            api_sender,
+            registered_commands: HashMap::new(),
+            event_subscribers: HashMap::new(),
+            next_request_id: 0,
+            pending_requests: HashMap::new(),
+        }
     }
 
     pub fn discover_plugins_in(&mut self, directory: &PathBuf) -> Result<()> {
@@ -36,78 +65,101 @@ impl PluginManager {
 
         for entry in WalkDir::new(directory).min_depth(1).max_depth(2).into_iter().filter_map(|e| e.ok()) {
             if entry.file_name().to_str() == Some("plugin.toml") {
-                let path = entry.path().to_path_buf();
-                match PluginManifest::load_from(&path) {
-                    Ok(manifest) => {
-                        let plugin_dir = path.parent().unwrap();
-                        let entrypoint_path = plugin_dir.join(&manifest.entrypoint);
-
-                        if !entrypoint_path.exists() {
-                            log::warn!("Plugin entrypoint not found for '{}': {:?}", manifest.name, entrypoint_path);
-                            continue;
-                        }
+                self.load_plugin_from_manifest(&entry.path().to_path_buf());
+            }
+        }
+
+        Ok(())
+    }
+
+    // Carrega um único `plugin.toml`, registrando seus comandos e eventos.
+    // Usado tanto pela descoberta em massa quanto pelo recarregamento de um único plugin.
+    fn load_plugin_from_manifest(&mut self, path: &PathBuf) {
+        match PluginManifest::load_from(path) {
+            Ok(manifest) => {
+                let plugin_dir = path.parent().unwrap();
+                let entrypoint_path = plugin_dir.join(&manifest.entrypoint);
 
-                        let plugin_idx = self.loaded_plugins.len();
-                        let helix_api = HelixApi::new(self.api_sender.clone(), plugin_idx);
-
-                        let host = if entrypoint_path.extension().map_or(false, |ext| ext == "wasm") {
-                            match WasmHost::new(&entrypoint_path, helix_api) {
-                                Ok(mut host) => {
-                                    // Chamar a função de inicialização do plugin
-                                    if let Err(e) = host.call_function("on_load", &[]) {
-                                        log::error!("Error calling on_load for plugin '{}': {}", manifest.name, e);
-                                    }
-                                    PluginHost::Wasm(host)
-                                }
-                                Err(e) => {
-                                    log::error!("Failed to load wasm host for plugin '{}': {}", manifest.name, e);
-                                    continue;
-                                }
+                if !entrypoint_path.exists() {
+                    log::warn!("Plugin entrypoint not found for '{}': {:?}", manifest.name, entrypoint_path);
+                    return;
+                }
+
+                let plugin_id = PluginId(self.next_plugin_id);
+                self.next_plugin_id += 1;
+                let helix_api = HelixApi::new(
+                    self.api_sender.clone(),
+                    plugin_id.0 as usize,
+                    manifest.permissions.clone(),
+                );
+
+                let host = if entrypoint_path.extension().map_or(false, |ext| ext == "wasm") {
+                    match WasmHost::new(&entrypoint_path, helix_api) {
+                        Ok(mut host) => {
+                            // Chamar a função de inicialização do plugin
+                            if let Err(e) = host.call_function("on_load", &[]) {
+                                log::error!("Error calling on_load for plugin '{}': {}", manifest.name, e);
                             }
-                        } else if entrypoint_path.extension().map_or(false, |ext| ext == "lua") {
-                            match LuaHost::new(&entrypoint_path, helix_api) {
-                                Ok(mut host) => {
-                                    // Chamar a função de inicialização do plugin
-                                    if let Err(e) = host.call_function("on_load", &[]) {
-                                        log::error!("Error calling on_load for plugin '{}': {}", manifest.name, e);
-                                    }
-                                    PluginHost::Lua(host)
-                                }
-                                Err(e) => {
-                                    log::error!("Failed to load lua host for plugin '{}': {}", manifest.name, e);
-                                    continue;
-                                }
+                            PluginHost::Wasm(host)
+                        }
+                        Err(e) => {
+                            log::error!("Failed to load wasm host for plugin '{}': {}", manifest.name, e);
+                            return;
+                        }
+                    }
+                } else if entrypoint_path.extension().map_or(false, |ext| ext == "lua") {
+                    match LuaHost::new(&entrypoint_path, helix_api) {
+                        Ok(mut host) => {
+                            // Chamar a função de inicialização do plugin
+                            if let Err(e) = host.call_function("on_load", &[]) {
+                                log::error!("Error calling on_load for plugin '{}': {}", manifest.name, e);
                             }
-                        } else {
-                            log::warn!("Unsupported plugin entrypoint type for '{}': {:?}", manifest.name, entrypoint_path);
-                            continue;
-                        };
-
-                        log::info!("Successfully loaded plugin '{}'", manifest.name);
-
-                        self.loaded_plugins.push(LoadedPlugin { manifest, host });
-
-                        // Registrar comandos do plugin (se houver)
-                        // Isso será feito de forma mais genérica depois.
-                        // Por enquanto, apenas para o exemplo de teste.
-                        if self.loaded_plugins[plugin_idx].manifest.name == "my-first-plugin" {
-                            self.registered_commands.insert(
-                                "my-plugin:test-command".to_string(),
-                                ("on_saudacao_command".to_string(), plugin_idx),
-                            );
+                            PluginHost::Lua(host)
+                        }
+                        Err(e) => {
+                            log::error!("Failed to load lua host for plugin '{}': {}", manifest.name, e);
+                            return;
                         }
                     }
-                    Err(e) => log::error!("Failed to load plugin manifest from {:?}: {}", path, e),
+                } else {
+                    log::warn!("Unsupported plugin entrypoint type for '{}': {:?}", manifest.name, entrypoint_path);
+                    return;
+                };
+
+                log::info!("Successfully loaded plugin '{}'", manifest.name);
+
+                // Registrar os comandos e eventos declarados em `plugin.toml`, para
+                // qualquer plugin (WASM ou Lua), não apenas o plugin de demonstração.
+                let commands = manifest.commands.iter().map(|command| {
+                    (command.name.clone(), command.callback.clone())
+                }).collect::<Vec<_>>();
+                let events = manifest.events.iter().map(|event| {
+                    (event.name.clone(), event.callback.clone())
+                }).collect::<Vec<_>>();
+
+                self.loaded_plugins.insert(plugin_id, LoadedPlugin {
+                    manifest,
+                    manifest_path: path.clone(),
+                    host,
+                });
+
+                for (name, callback) in commands {
+                    self.register_command(name, callback, plugin_id);
+                }
+                for (name, callback) in events {
+                    self.subscribe_to_event(name, callback, plugin_id);
                 }
             }
+            Err(e) => log::error!("Failed to load plugin manifest from {:?}: {}", path, e),
         }
-
-        Ok(())
     }
 
     pub fn execute_command(&mut self, name: &str, args: &[String]) {
-        if let Some((callback_fn_name, plugin_idx)) = self.registered_commands.get(name) {
-            let plugin = &mut self.loaded_plugins[*plugin_idx];
+        if let Some((callback_fn_name, plugin_id)) = self.registered_commands.get(name) {
+            let plugin = match self.loaded_plugins.get_mut(plugin_id) {
+                Some(plugin) => plugin,
+                None => return,
+            };
             match &mut plugin.host {
                 PluginHost::Wasm(host) => {
                     if let Err(e) = host.call_function(callback_fn_name, args) {
@@ -125,14 +177,17 @@ impl PluginManager {
         }
     }
 
-    pub fn register_command(&mut self, command_name: String, callback_function_name: String, plugin_idx: usize) {
-        self.registered_commands.insert(command_name, (callback_function_name, plugin_idx));
+    pub fn register_command(&mut self, command_name: String, callback_function_name: String, plugin_id: PluginId) {
+        self.registered_commands.insert(command_name.clone(), (callback_function_name, plugin_id));
         log::info!("Registered plugin command: {}", command_name);
     }
 
     pub fn handle_plugin_response(&mut self, request_id: u32, response_data: String) {
-        if let Some((plugin_idx, callback_fn_name)) = self.pending_requests.remove(&request_id) {
-            let plugin = &mut self.loaded_plugins[plugin_idx];
+        if let Some((plugin_id, callback_fn_name, _deadline)) = self.pending_requests.remove(&request_id) {
+            let plugin = match self.loaded_plugins.get_mut(&plugin_id) {
+                Some(plugin) => plugin,
+                None => return,
+            };
             match &mut plugin.host {
                 PluginHost::Wasm(host) => {
                     if let Err(e) = host.on_response(request_id, response_data.clone()) {
@@ -150,9 +205,9 @@ impl PluginManager {
         }
     }
 
-    pub fn subscribe_to_event(&mut self, event_name: String, callback_function_name: String, plugin_idx: usize) {
-        self.event_subscribers.entry(event_name).or_default().push((plugin_idx, callback_function_name));
-        log::info!("Plugin {} subscribed to event '{}'".to_string(), plugin_idx, event_name);
+    pub fn subscribe_to_event(&mut self, event_name: String, callback_function_name: String, plugin_id: PluginId) {
+        log::info!("Plugin {} subscribed to event '{}'", plugin_id, event_name);
+        self.event_subscribers.entry(event_name).or_default().push((plugin_id, callback_function_name));
     }
 
     pub fn get_next_request_id(&mut self) -> u32 {
@@ -161,14 +216,61 @@ impl PluginManager {
         id
     }
 
-    pub fn add_pending_request(&mut self, request_id: u32, plugin_idx: usize, callback_fn_name: String) {
-        self.pending_requests.insert(request_id, (plugin_idx, callback_fn_name));
+    pub fn add_pending_request(&mut self, request_id: u32, plugin_id: PluginId, callback_fn_name: String) {
+        let timeout_secs = self
+            .loaded_plugins
+            .get(&plugin_id)
+            .map_or(crate::config::default_request_timeout(), |plugin| plugin.manifest.timeout);
+        let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+        self.pending_requests.insert(request_id, (plugin_id, callback_fn_name, deadline));
+    }
+
+    /// Removes pending requests whose deadline has passed and notifies their plugin with a
+    /// structured timeout error, so a request that never gets a response doesn't leak forever
+    /// and the plugin gets a chance to recover instead of waiting indefinitely.
+    pub fn sweep_timeouts(&mut self) {
+        let now = Instant::now();
+        let expired: Vec<u32> = self
+            .pending_requests
+            .iter()
+            .filter(|(_, (_, _, deadline))| *deadline <= now)
+            .map(|(request_id, _)| *request_id)
+            .collect();
+
+        for request_id in expired {
+            let Some((plugin_id, callback_fn_name, _)) = self.pending_requests.remove(&request_id) else {
+                continue;
+            };
+
+            let Some(plugin) = self.loaded_plugins.get_mut(&plugin_id) else {
+                continue;
+            };
+
+            let timeout_error =
+                format!(r#"{{"error":"timeout","request_id":{}}}"#, request_id);
+
+            let result = match &mut plugin.host {
+                PluginHost::Wasm(host) => host.on_response(request_id, timeout_error),
+                PluginHost::Lua(host) => host.on_response(request_id, timeout_error),
+            };
+            if let Err(e) = result {
+                log::error!(
+                    "Error executing timeout callback '{}' for plugin '{}': {}",
+                    callback_fn_name,
+                    plugin.manifest.name,
+                    e
+                );
+            }
+        }
     }
 
     pub fn dispatch_event(&mut self, event_name: &str, event_data: &str) {
         if let Some(subscribers) = self.event_subscribers.get(event_name) {
-            for (plugin_idx, callback_fn_name) in subscribers.clone() {
-                let plugin = &mut self.loaded_plugins[plugin_idx];
+            for (plugin_id, callback_fn_name) in subscribers.clone() {
+                let plugin = match self.loaded_plugins.get_mut(&plugin_id) {
+                    Some(plugin) => plugin,
+                    None => continue,
+                };
                 match &mut plugin.host {
                     PluginHost::Wasm(host) => {
                         if let Err(e) = host.call_function(&callback_fn_name, &[event_data.to_string()]) {
@@ -184,5 +286,50 @@ impl PluginManager {
             }
         }
     }
+
+    /// Tears down a loaded plugin: calls its `on_unload` hook, drops its host, and removes every
+    /// command/event/pending-request entry that referenced it. Other plugins' `PluginId`s are
+    /// untouched, since they're not positional.
+    pub fn unload_plugin(&mut self, plugin_id: PluginId) -> Result<()> {
+        let Some(mut plugin) = self.loaded_plugins.remove(&plugin_id) else {
+            return Ok(());
+        };
+
+        if let Err(e) = match &mut plugin.host {
+            PluginHost::Wasm(host) => host.call_function("on_unload", &[]),
+            PluginHost::Lua(host) => host.call_function("on_unload", &[]),
+        } {
+            log::error!("Error calling on_unload for plugin '{}': {}", plugin.manifest.name, e);
+        }
+
+        self.registered_commands.retain(|_, (_, id)| *id != plugin_id);
+        self.event_subscribers.retain(|_, subscribers| {
+            subscribers.retain(|(id, _)| *id != plugin_id);
+            !subscribers.is_empty()
+        });
+        self.pending_requests.retain(|_, (id, _, _)| *id != plugin_id);
+
+        log::info!("Unloaded plugin '{}'", plugin.manifest.name);
+
+        Ok(())
+    }
+
+    /// Unloads the named plugin and loads it again from its `plugin.toml`, so a plugin author
+    /// can iterate on a plugin without restarting the editor.
+    pub fn reload_plugin(&mut self, name: &str) -> Result<()> {
+        let Some((&plugin_id, manifest_path)) = self
+            .loaded_plugins
+            .iter()
+            .find(|(_, plugin)| plugin.manifest.name == name)
+            .map(|(id, plugin)| (id, plugin.manifest_path.clone()))
+        else {
+            log::warn!("Cannot reload unknown plugin: {}", name);
+            return Ok(());
+        };
+
+        self.unload_plugin(plugin_id)?;
+        self.load_plugin_from_manifest(&manifest_path);
+
+        Ok(())
+    }
 }
-}
\ No newline at end of file
@@ -17,6 +17,7 @@ pub use tree_cursor::TreeCursor;
 mod config;
 pub mod highlighter;
 pub mod highlighter2;
+pub mod merge;
 mod parse;
 mod pretty_print;
 mod query_iter;
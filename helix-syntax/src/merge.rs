@@ -28,6 +28,29 @@ pub fn merge<I: Iterator<Item = HighlightEvent>>(
     merge
 }
 
+/// Merges several span layers (e.g. diagnostics, selections, search matches, plugin-provided
+/// decorations) into the highlight event stream, in a deterministic priority order.
+///
+/// `layers` is ordered from lowest to highest priority. Each layer is merged in with [`merge`],
+/// nesting its `HighlightStart`/`HighlightEnd` pairs inside whatever came before, so for
+/// overlapping ranges the later (higher-priority) layer ends up innermost. Consumers that resolve
+/// overlapping highlights by folding a highlight stack (e.g. `Style::patch` applied in nesting
+/// order, as in `highlighted_code_block`) therefore let the highest-priority layer win instead of
+/// one layer silently clobbering another.
+pub fn merge_layered<I>(
+    iter: I,
+    layers: Vec<Vec<(usize, std::ops::Range<usize>)>>,
+) -> Box<dyn Iterator<Item = HighlightEvent>>
+where
+    I: Iterator<Item = HighlightEvent> + 'static,
+{
+    layers
+        .into_iter()
+        .fold(Box::new(iter) as Box<dyn Iterator<Item = HighlightEvent>>, |acc, spans| {
+            Box::new(merge(acc, spans))
+        })
+}
+
 impl<I: Iterator<Item = HighlightEvent>> Iterator for Merge<I> {
     type Item = HighlightEvent;
     fn next(&mut self) -> Option<Self::Item> {
@@ -26,6 +26,7 @@ use crate::{
     handlers,
     job::Jobs,
     keymap::Keymaps,
+    mousemap::Mousemaps,
     ui::{self, overlay::overlaid},
 };
 
@@ -128,7 +129,13 @@ impl Application {
         let keys = Box::new(Map::new(Arc::clone(&config), |config: &Config| {
             &config.keys
         }));
-        let editor_view = Box::new(ui::EditorView::new(Keymaps::new(keys)));
+        let mouse = Box::new(Map::new(Arc::clone(&config), |config: &Config| {
+            &config.mouse
+        }));
+        let editor_view = Box::new(ui::EditorView::new(
+            Keymaps::new(keys),
+            Mousemaps::new(mouse),
+        ));
         compositor.push(editor_view);
 
         if args.load_tutor {
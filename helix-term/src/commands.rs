@@ -1,5 +1,6 @@
 pub(crate) mod dap;
 pub(crate) mod lsp;
+pub(crate) mod mouse;
 pub(crate) mod typed;
 
 pub use dap::*;
@@ -291,6 +292,9 @@ impl MappableCommand {
         goto_file_hsplit, "Goto files in selection (hsplit)",
         goto_file_vsplit, "Goto files in selection (vsplit)",
         goto_reference, "Goto references",
+        goto_document_link, "Goto document link under cursor",
+        goto_next_document_link, "Goto next document link",
+        goto_prev_document_link, "Goto previous document link",
         goto_window_top, "Goto window top",
         goto_window_center, "Goto window center",
         goto_window_bottom, "Goto window bottom",
@@ -1677,7 +1681,7 @@ fn searcher(cx: &mut Context, direction: Direction) {
             completions
                 .iter()
                 .filter(|comp| comp.starts_with(input))
-                .map(|comp| (0.., std::borrow::Cow::Owned(comp.clone())))
+                .map(|comp| (0.., std::borrow::Cow::Owned(comp.clone()), None))
                 .collect()
         },
         move |view, doc, regex, event| {
@@ -1820,7 +1824,7 @@ fn global_search(cx: &mut Context) {
             completions
                 .iter()
                 .filter(|comp| comp.starts_with(input))
-                .map(|comp| (0.., std::borrow::Cow::Owned(comp.clone())))
+                .map(|comp| (0.., std::borrow::Cow::Owned(comp.clone()), None))
                 .collect()
         },
         move |_view, _doc, regex, event| {
@@ -4630,6 +4634,14 @@ fn shell_impl(
 }
 
 fn shell(cx: &mut compositor::Context, cmd: &str, behavior: &ShellBehavior) {
+    let (workspace, _) = helix_loader::find_workspace();
+    if !helix_view::trust::is_allowed(&workspace, helix_view::trust::TrustCapability::SHELL) {
+        cx.editor.set_error(
+            "Shell commands are disabled for this workspace — run `:trust` to allow them",
+        );
+        return;
+    }
+
     let pipe = match behavior {
         ShellBehavior::Replace | ShellBehavior::Ignore => true,
         ShellBehavior::Insert | ShellBehavior::Append => false,
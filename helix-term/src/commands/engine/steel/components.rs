@@ -284,7 +284,7 @@ pub fn helix_component_module(generate_sources: bool) -> BuiltInModule {
         .register_fn("buffer/clear", |buffer: &mut Buffer, area: Rect| {
             for x in area.left()..area.right() {
                 for y in area.top()..area.bottom() {
-                    if let Some(cell) = buffer.get_mut(x, y) {
+                    if let Some(cell) = buffer.cell_mut((x, y)) {
                         cell.reset()
                     };
                 }
@@ -295,7 +295,7 @@ pub fn helix_component_module(generate_sources: bool) -> BuiltInModule {
             |buffer: &mut Buffer, area: Rect, style: Style| {
                 for x in area.left()..area.right() {
                     for y in area.top()..area.bottom() {
-                        let cell = buffer.get_mut(x, y);
+                        let cell = buffer.cell_mut((x, y));
                         if let Some(cell) = cell {
                             cell.reset();
                             cell.set_style(style);
@@ -11,7 +11,7 @@ use helix_lsp::{
 use tokio_stream::StreamExt;
 use tui::{text::Span, widgets::Row};
 
-use super::{align_view, push_jump, Align, Context, Editor};
+use super::{align_view, goto_pos, push_jump, Align, Context, Editor};
 
 use helix_core::{
     diagnostic::DiagnosticProvider, syntax::config::LanguageServerFeature,
@@ -23,7 +23,7 @@ use helix_view::{
     editor::Action,
     handlers::lsp::SignatureHelpInvoked,
     theme::Style,
-    Document, View,
+    Document, DocumentId, View,
 };
 
 use crate::{
@@ -1014,6 +1014,122 @@ pub fn goto_reference(cx: &mut Context) {
     });
 }
 
+/// Finds the index (into `doc.document_links`) of the cached link containing `pos`, if any.
+fn document_link_at(doc: &Document, pos: usize) -> Option<usize> {
+    doc.document_links
+        .iter()
+        .position(|link| (link.start..link.end).contains(&pos))
+}
+
+/// Returns a clone of the document link at `link_idx`, resolving its `target` via
+/// `documentLink/resolve` first if the server hasn't sent one up front. The cached entry is
+/// updated in place with the resolved link so later lookups don't need to resolve again.
+fn resolve_document_link(
+    editor: &mut Editor,
+    doc_id: DocumentId,
+    link_idx: usize,
+) -> Option<lsp::DocumentLink> {
+    let entry = editor.document(doc_id)?.document_links.get(link_idx)?;
+    if entry.link.target.is_some() {
+        return Some(entry.link.clone());
+    }
+
+    let language_server_id = entry.language_server_id;
+    let unresolved = entry.link.clone();
+    let future = editor
+        .language_server_by_id(language_server_id)
+        .and_then(|language_server| language_server.document_link_resolve(unresolved))?;
+
+    match helix_lsp::block_on(future) {
+        Ok(resolved) => {
+            if let Some(doc) = editor.document_mut(doc_id) {
+                if let Some(entry) = doc.document_links.get_mut(link_idx) {
+                    entry.link = resolved.clone();
+                }
+            }
+            Some(resolved)
+        }
+        Err(err) => {
+            editor.set_error(format!("documentLink/resolve failed: {err}"));
+            None
+        }
+    }
+}
+
+/// Opens the resolved link target: `file:` URIs are opened as a document, everything else is
+/// handed off to the external-url opener.
+pub(crate) fn open_document_link(
+    editor: &mut Editor,
+    jobs: &mut crate::job::Jobs,
+    doc_id: DocumentId,
+    link_idx: usize,
+) {
+    let Some(target) = resolve_document_link(editor, doc_id, link_idx).and_then(|link| link.target)
+    else {
+        editor.set_error("Document link target could not be resolved");
+        return;
+    };
+
+    match helix_core::Uri::try_from(target.clone()) {
+        Ok(uri) => {
+            let Some(path) = uri.as_path() else {
+                editor.set_error(format!("unable to convert URI to filepath: {target}"));
+                return;
+            };
+            if let Err(err) = editor.open(path, Action::Replace) {
+                editor.set_error(format!("Open file failed: {:?}", err));
+            }
+        }
+        Err(_) => jobs.callback(crate::open_external_url_callback(target)),
+    }
+}
+
+pub fn goto_document_link(cx: &mut Context) {
+    let (view, doc) = current_ref!(cx.editor);
+    let cursor = doc.selection(view.id).primary().cursor(doc.text().slice(..));
+
+    let Some(link_idx) = document_link_at(doc, cursor) else {
+        cx.editor.set_error("No document link under the cursor");
+        return;
+    };
+    let doc_id = doc.id();
+
+    open_document_link(cx.editor, cx.jobs, doc_id, link_idx);
+}
+
+fn goto_adjacent_document_link(cx: &mut Context, forward: bool) {
+    let (view, doc) = current_ref!(cx.editor);
+    let cursor = doc.selection(view.id).primary().cursor(doc.text().slice(..));
+
+    let link = if forward {
+        doc.document_links
+            .iter()
+            .find(|link| link.start > cursor)
+            .or_else(|| doc.document_links.first())
+    } else {
+        doc.document_links
+            .iter()
+            .rev()
+            .find(|link| link.end < cursor)
+            .or_else(|| doc.document_links.last())
+    };
+
+    let Some(start) = link.map(|link| link.start) else {
+        cx.editor.set_error("No document links in this buffer");
+        return;
+    };
+
+    goto_pos(cx.editor, start);
+}
+
+pub fn goto_next_document_link(cx: &mut Context) {
+    goto_adjacent_document_link(cx, true);
+}
+
+pub fn goto_prev_document_link(cx: &mut Context) {
+    goto_adjacent_document_link(cx, false);
+}
+
 pub fn signature_help(cx: &mut Context) {
     cx.editor
         .handlers
@@ -1024,10 +1140,15 @@ pub fn hover(cx: &mut Context) {
     use ui::lsp::hover::Hover;
 
     let (view, doc) = current!(cx.editor);
-    if doc
-        .language_servers_with_feature(LanguageServerFeature::Hover)
-        .count()
-        == 0
+    let cursor = doc.selection(view.id).primary().cursor(doc.text().slice(..));
+    let doc_id = doc.id();
+    let link_idx = document_link_at(doc, cursor);
+
+    if link_idx.is_none()
+        && doc
+            .language_servers_with_feature(LanguageServerFeature::Hover)
+            .count()
+            == 0
     {
         cx.editor
             .set_error("No configured language server supports hover");
@@ -1062,6 +1183,12 @@ pub fn hover(cx: &mut Context) {
         }
 
         let call = move |editor: &mut Editor, compositor: &mut Compositor| {
+            if let Some(link_idx) = link_idx {
+                if let Some(link) = resolve_document_link(editor, doc_id, link_idx) {
+                    hovers.push(("document-link".to_string(), document_link_hover(&link)));
+                }
+            }
+
             if hovers.is_empty() {
                 editor.set_status("No hover results available.");
                 return;
@@ -1076,6 +1203,28 @@ pub fn hover(cx: &mut Context) {
     });
 }
 
+/// Builds a synthetic `lsp::Hover` entry showing a document link's target and, if the server
+/// provided one, its `tooltip` text. Used to surface links (which have no `hover` request of
+/// their own) through the same popup as LSP hover results.
+fn document_link_hover(link: &lsp::DocumentLink) -> lsp::Hover {
+    let target = link
+        .target
+        .as_ref()
+        .map(|target| target.to_string())
+        .unwrap_or_else(|| "<unresolved>".to_string());
+
+    let mut contents = format!("[{target}]({target})");
+    if let Some(tooltip) = &link.tooltip {
+        contents.push_str("\n\n");
+        contents.push_str(tooltip);
+    }
+
+    lsp::Hover {
+        contents: lsp::HoverContents::Scalar(lsp::MarkedString::String(contents)),
+        range: None,
+    }
+}
+
 pub fn rename_symbol(cx: &mut Context) {
     fn get_prefill_from_word_boundary(editor: &Editor) -> String {
         let (view, doc) = current_ref!(editor);
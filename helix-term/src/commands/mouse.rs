@@ -4,11 +4,13 @@ use crate::ui::{
 };
 use anyhow::anyhow;
 use helix_core::{movement::Direction, Range, Selection};
-use helix_view::{input::MouseEvent, Document, ViewId};
+use helix_view::{clipboard::ClipboardType, input::MouseEvent, Document, ViewId};
+use serde::Deserialize;
 
 use super::{
     move_next_long_word_end, move_next_word_end, move_prev_long_word_start, move_prev_word_start,
-    paste_primary_clipboard_before, yank_primary_selection_impl, Context,
+    paste_primary_clipboard_before, replace_selections_with_clipboard_impl,
+    yank_main_selection_to_clipboard_impl, Context,
 };
 
 #[derive(Eq, PartialEq, PartialOrd, Ord, Clone, Debug, Copy)]
@@ -30,6 +32,33 @@ impl std::str::FromStr for StaticMouseCommand {
     }
 }
 
+impl<'de> Deserialize<'de> for StaticMouseCommand {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+macro_rules! static_mouse_commands {
+    ( $($name:ident, $doc:literal,)* ) => {
+        $(
+            #[allow(non_upper_case_globals)]
+            pub const $name: Self = Self {
+                name: stringify!($name),
+                fun: $name,
+                doc: $doc,
+            };
+        )*
+
+        pub const STATIC_COMMAND_LIST: &'static [Self] = &[
+            $( Self::$name, )*
+        ];
+    }
+}
+
 impl StaticMouseCommand {
     pub fn execute(&self, cx: &mut Context, event: &MouseEvent, editor_view: &mut EditorView) {
         (self.fun)(cx, event, editor_view);
@@ -42,9 +71,28 @@ impl StaticMouseCommand {
     pub fn doc(&self) -> &str {
         self.doc
     }
+
+    #[rustfmt::skip]
+    static_mouse_commands!(
+        handle_main_button_mouse, "Set selection or toggle breakpoint",
+        set_mouse_selection, "Set selection at clicked position",
+        select_word_mouse, "Select the word under the cursor",
+        select_long_word_mouse, "Select the long word (WORD) under the cursor",
+        select_line_mouse, "Select the line under the cursor",
+        drag_mouse_selection, "Extend the selection to the dragged position",
+        scroll_up_mouse, "Scroll the view up",
+        scroll_down_mouse, "Scroll the view down",
+        paste_primary_clipboard_before_mouse, "Paste the primary clipboard before the clicked position",
+        replace_selections_with_primary_clipboard_mouse, "Replace selections with the primary clipboard",
+        yank_main_selection_to_primary_clipboard_mouse, "Yank the main selection to the primary clipboard",
+        add_breakpoint_mouse, "Toggle a breakpoint in the gutter",
+        add_selection_mouse, "Add a new selection at the clicked position",
+        dap_edit_condition_mouse, "Edit a breakpoint's condition",
+        dap_edit_log_mouse, "Edit a breakpoint's log message",
+    );
 }
 
-fn handle_selection_in_buffer(
+pub(crate) fn handle_selection_in_buffer(
     cx: &mut Context,
     evt: &MouseEvent,
     ev: &mut EditorView,
@@ -80,7 +128,10 @@ pub fn handle_main_button_mouse(cx: &mut Context, evt: &MouseEvent, ev: &mut Edi
         },
     ) {
         add_breakpoint_mouse(cx, evt, ev);
+        return;
     }
+
+    record_drag_anchor(cx, false);
 }
 
 pub fn set_mouse_selection(cx: &mut Context, evt: &MouseEvent, ev: &mut EditorView) {
@@ -92,16 +143,109 @@ pub fn set_mouse_selection(cx: &mut Context, evt: &MouseEvent, ev: &mut EditorVi
             doc.set_selection(*view_id, Selection::point(pos));
         },
     );
+
+    record_drag_anchor(cx, false);
 }
 
-pub fn select_word_mouse(cx: &mut Context, _: &MouseEvent, _: &mut EditorView) {
+/// Records the current primary selection as the anchor for a subsequent
+/// [`MouseEventKind::Drag`], so dragging after a click/double-click/triple-click extends the
+/// selection from where the mouse went down rather than from wherever the cursor already was.
+fn record_drag_anchor(cx: &mut Context, line_wise: bool) {
+    let (view, doc) = current!(cx.editor);
+    cx.editor.mouse_down_range = Some(doc.selection(view.id).primary());
+    cx.editor.mouse_line_select = line_wise;
+}
+
+pub fn select_word_mouse(cx: &mut Context, evt: &MouseEvent, ev: &mut EditorView) {
+    handle_selection_in_buffer(
+        cx,
+        evt,
+        ev,
+        |doc: &mut Document, view_id: &ViewId, pos: usize| {
+            doc.set_selection(*view_id, Selection::point(pos));
+        },
+    );
     move_prev_word_start(cx);
     move_next_word_end(cx);
+    record_drag_anchor(cx, false);
 }
 
-pub fn select_long_word_mouse(cx: &mut Context, _: &MouseEvent, _: &mut EditorView) {
+pub fn select_long_word_mouse(cx: &mut Context, evt: &MouseEvent, ev: &mut EditorView) {
+    handle_selection_in_buffer(
+        cx,
+        evt,
+        ev,
+        |doc: &mut Document, view_id: &ViewId, pos: usize| {
+            doc.set_selection(*view_id, Selection::point(pos));
+        },
+    );
     move_prev_long_word_start(cx);
     move_next_long_word_end(cx);
+    record_drag_anchor(cx, false);
+}
+
+pub fn select_line_mouse(cx: &mut Context, evt: &MouseEvent, ev: &mut EditorView) {
+    handle_selection_in_buffer(
+        cx,
+        evt,
+        ev,
+        |doc: &mut Document, view_id: &ViewId, pos: usize| {
+            doc.set_selection(*view_id, Selection::point(pos));
+        },
+    );
+
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text();
+    let line = text.char_to_line(doc.selection(view.id).primary().head);
+    let start = text.line_to_char(line);
+    let end = text.line_to_char((line + 1).min(text.len_lines()));
+    let view_id = view.id;
+    doc.set_selection(view_id, Selection::single(start, end));
+
+    record_drag_anchor(cx, true);
+}
+
+/// Extends the selection from the anchor recorded by a preceding click (see
+/// [`record_drag_anchor`]) to the current drag position. Drags that cross into a different view
+/// than the one the mouse went down in are ignored.
+pub fn drag_mouse_selection(cx: &mut Context, evt: &MouseEvent, _: &mut EditorView) {
+    let line_wise = cx.editor.mouse_line_select;
+    let Some(anchor) = cx.editor.mouse_down_range else {
+        return;
+    };
+
+    let editor = &mut cx.editor;
+    let focused_view = view!(editor).id;
+    let Some((pos, view_id)) = pos_and_view(editor, evt.row, evt.column, true) else {
+        return;
+    };
+    if view_id != focused_view {
+        return;
+    }
+
+    let doc = doc_mut!(editor, &view!(editor, view_id).doc);
+    let text = doc.text();
+
+    let selection = if line_wise {
+        let anchor_line = text.char_to_line(anchor.from());
+        let drag_line = text.char_to_line(pos);
+        if drag_line >= anchor_line {
+            Selection::single(
+                text.line_to_char(anchor_line),
+                text.line_to_char((drag_line + 1).min(text.len_lines())),
+            )
+        } else {
+            Selection::single(
+                text.line_to_char((anchor_line + 1).min(text.len_lines())),
+                text.line_to_char(drag_line),
+            )
+        }
+    } else {
+        Selection::single(anchor.anchor, pos)
+    };
+
+    doc.set_selection(view_id, selection);
+    editor.ensure_cursor_in_view(view_id);
 }
 
 pub fn scroll_mouse_impl(cx: &mut Context, evt: &MouseEvent, dir: Direction, _: &mut EditorView) {
@@ -140,6 +284,15 @@ pub fn paste_primary_clipboard_before_mouse(
     }
 }
 
+pub fn replace_selections_with_primary_clipboard_mouse(
+    cx: &mut Context,
+    _: &MouseEvent,
+    _: &mut EditorView,
+) {
+    let _ =
+        replace_selections_with_clipboard_impl(cx.editor, ClipboardType::Selection, cx.count());
+}
+
 pub fn yank_main_selection_to_primary_clipboard_mouse(
     cx: &mut Context,
     _: &MouseEvent,
@@ -157,7 +310,7 @@ pub fn yank_main_selection_to_primary_clipboard_mouse(
         return;
     }
 
-    yank_primary_selection_impl(cx.editor, '*');
+    let _ = yank_main_selection_to_clipboard_impl(cx.editor, ClipboardType::Selection);
 }
 
 pub fn add_breakpoint_mouse(cx: &mut Context, evt: &MouseEvent, _: &mut EditorView) {
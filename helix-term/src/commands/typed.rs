@@ -1463,6 +1463,65 @@ fn lsp_stop(
     Ok(())
 }
 
+fn trust_workspace(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (workspace, _) = helix_loader::find_workspace();
+    cx.push_layer(Box::new(ui::TrustPrompt::new(
+        workspace,
+        |_compositor, editor, decision| match decision {
+            Some(capabilities) => {
+                editor.set_status(format!("Trust updated: {capabilities:?}"));
+                // Pick up a newly-trusted local config or language servers immediately.
+                for doc_id in editor.documents().map(|doc| doc.id()).collect::<Vec<_>>() {
+                    editor.refresh_language_servers(doc_id);
+                }
+            }
+            None => editor.set_status("Trust decision cancelled"),
+        },
+    )));
+
+    Ok(())
+}
+
+fn lsp_workspace_remove(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (_view, doc) = current!(cx.editor);
+
+    let root = match args.first() {
+        Some(arg) => helix_stdx::path::canonicalize(std::path::Path::new(arg.as_ref())),
+        None => doc
+            .path()
+            .and_then(|path| path.parent())
+            .map(|path| path.to_path_buf())
+            .context("Current document has no path to derive a workspace folder from")?,
+    };
+    let root_uri = helix_lsp::lsp::Url::from_file_path(&root)
+        .map_err(|_| anyhow::anyhow!("Could not construct URI for path {}", root.display()))?;
+
+    for ls in doc.language_servers() {
+        ls.remove_workspace_folder(Some(root_uri.clone()));
+    }
+
+    cx.editor
+        .set_status(format!("Removed workspace folder {}", root.display()));
+
+    Ok(())
+}
+
 fn tree_sitter_scopes(
     cx: &mut compositor::Context,
     _args: &[Cow<str>],
@@ -2599,6 +2658,20 @@ pub const TYPABLE_COMMAND_LIST: &[TypableCommand] = &[
             fun: lsp_stop,
             signature: CommandSignature::none(),
         },
+        TypableCommand {
+            name: "trust",
+            aliases: &[],
+            doc: "Opens the workspace trust prompt to grant or revoke LSP, shell, and config trust for the current workspace.",
+            fun: trust_workspace,
+            signature: CommandSignature::none(),
+        },
+        TypableCommand {
+            name: "lsp-workspace-remove",
+            aliases: &[],
+            doc: "Removes a workspace folder from the Language Server in use by the current doc. Defaults to the current document's directory. Folders are added back automatically the next time a file outside the server's known roots is opened.",
+            fun: lsp_workspace_remove,
+            signature: CommandSignature::positional(&[completers::directory]),
+        },
         TypableCommand {
             name: "tree-sitter-scopes",
             aliases: &[],
@@ -2842,7 +2915,17 @@ pub(super) fn command_mode(cx: &mut Context) {
                 matches.sort_unstable_by_key(|(_file, score)| std::cmp::Reverse(*score));
                 matches
                     .into_iter()
-                    .map(|(name, _)| (0.., name.into()))
+                    .map(|(name, _)| {
+                        let doc = TYPABLE_COMMAND_MAP.get(name).map(|command| {
+                            if command.aliases.is_empty() {
+                                Cow::Borrowed(command.doc)
+                            } else {
+                                format!("{}\nAliases: {}", command.doc, command.aliases.join(", "))
+                                    .into()
+                            }
+                        });
+                        (0.., name.into(), doc)
+                    })
                     .collect()
             } else {
                 // Otherwise, use the command's completer and the last shellword
@@ -2864,13 +2947,13 @@ pub(super) fn command_mode(cx: &mut Context) {
                 {
                     completer(editor, part)
                         .into_iter()
-                        .map(|(range, file)| {
+                        .map(|(range, file, doc)| {
                             let file = shellwords::escape(file);
 
                             // offset ranges to input
                             let offset = input.len() - part_len;
                             let range = (range.start + offset)..;
-                            (range, file)
+                            (range, file, doc)
                         })
                         .collect()
                 } else {
@@ -1,5 +1,6 @@
 use crate::keymap;
 use crate::keymap::{merge_keys, KeyTrie};
+use crate::mousemap::{self, merge_mouse_keys, MouseTrieMapper};
 use helix_loader::merge_toml_values;
 use helix_view::{document::Mode, theme};
 use serde::Deserialize;
@@ -13,6 +14,10 @@ use toml::de::Error as TomlError;
 pub struct Config {
     pub theme: Option<theme::Config>,
     pub keys: HashMap<Mode, KeyTrie>,
+    /// Mouse bindings, configured under `[mouse.<mode>]`, e.g. `[mouse.normal]`, mirroring
+    /// `[keys.<mode>]` but keyed by mouse event (`"1-left"`, `"A-1-middle"`, `"scroll_up"`, ...)
+    /// instead of [`KeyEvent`](helix_view::input::KeyEvent).
+    pub mouse: HashMap<Mode, MouseTrieMapper>,
     pub editor: helix_view::editor::Config,
 }
 
@@ -21,6 +26,8 @@ pub struct Config {
 pub struct ConfigRaw {
     pub theme: Option<theme::Config>,
     pub keys: Option<HashMap<Mode, KeyTrie>>,
+    /// See [`Config::mouse`].
+    pub mouse: Option<HashMap<Mode, MouseTrieMapper>>,
     pub editor: Option<toml::Value>,
 }
 
@@ -29,6 +36,7 @@ impl Default for Config {
         Config {
             theme: None,
             keys: keymap::default(),
+            mouse: mousemap::default(),
             editor: helix_view::editor::Config::default(),
         }
     }
@@ -74,6 +82,14 @@ impl Config {
                     merge_keys(&mut keys, local_keys)
                 }
 
+                let mut mouse = mousemap::default();
+                if let Some(global_mouse) = global.mouse {
+                    merge_mouse_keys(&mut mouse, &global_mouse)
+                }
+                if let Some(local_mouse) = local.mouse {
+                    merge_mouse_keys(&mut mouse, &local_mouse)
+                }
+
                 let editor = match (global.editor, local.editor) {
                     (None, None) => helix_view::editor::Config::default(),
                     (None, Some(val)) | (Some(val), None) => {
@@ -87,6 +103,7 @@ impl Config {
                 Config {
                     theme: local.theme.or(global.theme),
                     keys,
+                    mouse,
                     editor,
                 }
             }
@@ -100,9 +117,14 @@ impl Config {
                 if let Some(keymap) = config.keys {
                     merge_keys(&mut keys, keymap);
                 }
+                let mut mouse = mousemap::default();
+                if let Some(mousemap) = config.mouse {
+                    merge_mouse_keys(&mut mouse, &mousemap);
+                }
                 Config {
                     theme: config.theme,
                     keys,
+                    mouse,
                     editor: config.editor.map_or_else(
                         || Ok(helix_view::editor::Config::default()),
                         |val| val.try_into().map_err(ConfigLoadError::BadConfig),
@@ -120,8 +142,22 @@ impl Config {
     pub fn load_default() -> Result<Config, ConfigLoadError> {
         let global_config =
             fs::read_to_string(helix_loader::config_file()).map_err(ConfigLoadError::Error);
-        let local_config = fs::read_to_string(helix_loader::workspace_config_file())
-            .map_err(ConfigLoadError::Error);
+
+        let (workspace, _) = helix_loader::find_workspace();
+        let local_config = if helix_view::trust::is_allowed(
+            &workspace,
+            helix_view::trust::TrustCapability::CONFIG,
+        ) {
+            fs::read_to_string(helix_loader::workspace_config_file())
+                .map_err(ConfigLoadError::Error)
+        } else {
+            // Not trusted (or not yet decided): treat like a missing local config rather
+            // than failing outright, same as when `.helix/config.toml` doesn't exist.
+            Err(ConfigLoadError::Error(IOError::new(
+                std::io::ErrorKind::PermissionDenied,
+                "local workspace config is not trusted",
+            )))
+        };
         Config::load(global_config, local_config)
     }
 }
@@ -174,6 +210,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parsing_mouse_keymaps_config_file() {
+        use crate::mousemap;
+        use helix_core::hashmap;
+        use helix_view::document::Mode;
+
+        let sample_mousemaps = r#"
+            [mouse.normal]
+            "1-left" = "handle_main_button_mouse"
+            "scroll_up" = "scroll_up_mouse"
+        "#;
+
+        let mut mouse = mousemap::default();
+        merge_mouse_keys(
+            &mut mouse,
+            &hashmap! {
+                Mode::Normal => mousemap!({
+                    "1-left" => handle_main_button_mouse,
+                    "scroll_up" => scroll_up_mouse,
+                }),
+            },
+        );
+
+        assert_eq!(
+            Config::load_test(sample_mousemaps),
+            Config {
+                mouse,
+                ..Default::default()
+            }
+        );
+    }
+
     #[test]
     fn parsing_menus() {
         use crate::keymap;
@@ -14,12 +14,14 @@ use crate::handlers::signature_help::SignatureHelpHandler;
 pub use helix_view::handlers::{word_index, Handlers};
 
 use self::document_colors::DocumentColorsHandler;
+use self::document_links::DocumentLinksHandler;
 
 mod auto_reload;
 mod auto_save;
 pub mod completion;
 pub mod diagnostics;
 mod document_colors;
+mod document_links;
 mod prompt;
 mod signature_help;
 mod snippet;
@@ -32,6 +34,7 @@ pub fn setup(config: Arc<ArcSwap<Config>>) -> Handlers {
     let auto_save = AutoSaveHandler::new().spawn();
     let auto_reload = PollHandler::new().spawn();
     let document_colors = DocumentColorsHandler::default().spawn();
+    let document_links = DocumentLinksHandler::default().spawn();
     let word_index = word_index::Handler::spawn();
     let pull_diagnostics = PullDiagnosticsHandler::default().spawn();
     let pull_all_documents_diagnostics = PullAllDocumentsDiagnosticHandler::default().spawn();
@@ -42,6 +45,7 @@ pub fn setup(config: Arc<ArcSwap<Config>>) -> Handlers {
         auto_save,
         auto_reload,
         document_colors,
+        document_links,
         word_index,
         pull_diagnostics,
         pull_all_documents_diagnostics,
@@ -54,6 +58,7 @@ pub fn setup(config: Arc<ArcSwap<Config>>) -> Handlers {
     diagnostics::register_hooks(&handlers);
     snippet::register_hooks(&handlers);
     document_colors::register_hooks(&handlers);
+    document_links::register_hooks(&handlers);
     prompt::register_hooks(&handlers);
     auto_reload::register_hooks(&handlers, &config.load().editor);
     handlers
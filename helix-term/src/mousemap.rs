@@ -159,6 +159,11 @@ impl Mousemaps {
                     let res = self.get_from_event(values, &key.clone_without_coords());
                     return res;
                 }
+                MouseEventKind::Drag(_) => {
+                    // Dragging does not disambiguate click counts; look the binding up directly.
+                    let res = self.get_from_event(values, &key.clone_without_coords());
+                    return res;
+                }
                 _ => (),
             }
         }
@@ -198,7 +203,7 @@ mod tests {
     #[should_panic]
     fn duplicate_mouse_keys_should_panic() {
         mousemap!({
-            "1-left" => code_action,
+            "1-left" => select_word_mouse,
             "1-left" => add_selection_mouse,
         });
     }
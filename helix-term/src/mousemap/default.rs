@@ -11,7 +11,8 @@ pub fn default() -> HashMap<Mode, HashMap<MouseEvent, MouseTrie>> {
     let normal = mousemap!({
         "1-left" => handle_main_button_mouse,
         "2-left" => select_word_mouse,
-        "3-left" => select_long_word_mouse,
+        "3-left" => select_line_mouse,
+        "drag_left" => drag_mouse_selection,
         "A-1-left" => add_selection_mouse,
         "1-right" => yank_main_selection_to_primary_clipboard_mouse,
         "A-1-middle" => replace_selections_with_primary_clipboard_mouse,
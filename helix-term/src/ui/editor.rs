@@ -5,6 +5,7 @@ use crate::{
     handlers::completion::CompletionItem,
     key,
     keymap::{KeymapResult, Keymaps},
+    mousemap::{MousemapResult, Mousemaps},
     ui::{
         document::{render_document, LinePos, TextRenderer},
         statusline,
@@ -12,6 +13,7 @@ use crate::{
         Completion, ProgressSpinners,
     },
 };
+use crate::ui::mouse_context_menu::MouseContextMenu;
 
 use helix_core::{
     diagnostic::NumberOrString,
@@ -31,9 +33,11 @@ use helix_view::{
     icons::ICONS,
     input::{KeyEvent, MouseButton, MouseEvent, MouseEventKind},
     keyboard::{KeyCode, KeyModifiers},
-    Document, Editor, Theme, View,
+    Document, Editor, Theme, View, ViewId,
+};
+use std::{
+    mem::take, num::NonZeroUsize, ops, path::PathBuf, rc::Rc, sync::LazyLock, time::Duration,
 };
-use std::{mem::take, num::NonZeroUsize, ops, path::PathBuf, rc::Rc, sync::LazyLock};
 
 use tui::{
     buffer::Buffer as Surface,
@@ -42,6 +46,7 @@ use tui::{
 
 pub struct EditorView {
     pub keymaps: Keymaps,
+    pub mousemaps: Mousemaps,
     on_next_key: Option<(OnKeyCallback, OnKeyCallbackKind)>,
     pseudo_pending: Vec<KeyEvent>,
     pub(crate) last_insert: (commands::MappableCommand, Vec<InsertEvent>),
@@ -63,9 +68,10 @@ pub enum InsertEvent {
 }
 
 impl EditorView {
-    pub fn new(keymaps: Keymaps) -> Self {
+    pub fn new(keymaps: Keymaps, mousemaps: Mousemaps) -> Self {
         Self {
             keymaps,
+            mousemaps,
             on_next_key: None,
             pseudo_pending: Vec::new(),
             last_insert: (commands::MappableCommand::normal_mode, Vec::new()),
@@ -388,6 +394,16 @@ impl EditorView {
         ));
 
         Self::doc_diagnostics_highlights_into(doc, theme, &mut overlays);
+        let hovered_document_link = editor
+            .hovered_document_link
+            .filter(|&(doc_id, _)| doc_id == doc.id())
+            .map(|(_, link_idx)| link_idx);
+        Self::doc_document_links_highlights_into(
+            doc,
+            theme,
+            hovered_document_link,
+            &mut overlays,
+        );
 
         if is_focused {
             if let Some(tabstops) = Self::tabstop_highlights(doc, theme) {
@@ -695,6 +711,37 @@ impl EditorView {
         ]);
     }
 
+    /// Get highlight spans for the document's cached LSP document links, so that clickable
+    /// spans are visually distinguished from the surrounding text. `hovered` is the index (into
+    /// `doc.document_links`) of the link currently under the mouse pointer, if any, and is drawn
+    /// with the `ui.document-link.tooltip` scope as a hover affordance.
+    pub fn doc_document_links_highlights_into(
+        doc: &Document,
+        theme: &Theme,
+        hovered: Option<usize>,
+        overlay_highlights: &mut Vec<OverlayHighlights>,
+    ) {
+        if doc.document_links.is_empty() {
+            return;
+        }
+
+        if let Some(highlight) = theme.find_highlight_exact("ui.document-link") {
+            let ranges = doc
+                .document_links
+                .iter()
+                .map(|link| link.start..link.end)
+                .collect();
+
+            overlay_highlights.push(OverlayHighlights::Homogeneous { highlight, ranges });
+        }
+
+        if let Some(link) = hovered.and_then(|idx| doc.document_links.get(idx)) {
+            if let Some(highlight) = theme.find_highlight_exact("ui.document-link.tooltip") {
+                overlay_highlights.push(OverlayHighlights::single(highlight, link.start..link.end));
+            }
+        }
+    }
+
     /// Get highlight spans for selections in a document view.
     pub fn doc_selection_highlights(
         mode: Mode,
@@ -1344,6 +1391,38 @@ impl EditorView {
     }
 }
 
+/// Finds the view whose buffer contains `(row, column)` and the character position under it.
+pub fn pos_and_view(
+    editor: &Editor,
+    row: u16,
+    column: u16,
+    ignore_virtual_text: bool,
+) -> Option<(usize, ViewId)> {
+    editor.tree.views().find_map(|(view, _focus)| {
+        view.pos_at_screen_coords(&editor.documents[&view.doc], row, column, ignore_virtual_text)
+            .map(|pos| (pos, view.id))
+    })
+}
+
+/// Finds the index (into `doc.document_links`) of the cached link containing `pos`, if any.
+fn document_link_at(doc: &Document, pos: usize) -> Option<usize> {
+    doc.document_links
+        .iter()
+        .position(|link| (link.start..link.end).contains(&pos))
+}
+
+/// Finds the view whose gutter contains `(row, column)` and the gutter-local coordinates under it.
+pub fn gutter_coords_and_view(
+    editor: &Editor,
+    row: u16,
+    column: u16,
+) -> Option<(Position, ViewId)> {
+    editor.tree.views().find_map(|(view, _focus)| {
+        view.gutter_coords_at_screen_coords(row, column)
+            .map(|coords| (coords, view.id))
+    })
+}
+
 impl EditorView {
     /// must be called whenever the editor processed input that
     /// is not a `KeyEvent`. In these cases any pending keys/on next
@@ -1375,6 +1454,24 @@ impl EditorView {
         }
 
         let config = cxt.editor.config();
+
+        // Config-bound mouse commands (`[mouse.<mode>]`) take priority over the built-in
+        // defaults below, the same way `[keys.<mode>]` overrides default keybindings.
+        let mouse_idle = Duration::from_millis(config.mouse_idle_timeout);
+        match self.mousemaps.get(cxt.editor.mode, event, &mouse_idle) {
+            MousemapResult::Matched(command) => {
+                command.execute(cxt, event, self);
+                return EventResult::Consumed(None);
+            }
+            MousemapResult::MatchedSequence(commands) => {
+                for command in &commands {
+                    command.execute(cxt, event, self);
+                }
+                return EventResult::Consumed(None);
+            }
+            MousemapResult::NotFound => {}
+        }
+
         let MouseEvent {
             kind,
             row,
@@ -1383,30 +1480,19 @@ impl EditorView {
             ..
         } = *event;
 
-        let pos_and_view = |editor: &Editor, row, column, ignore_virtual_text| {
-            editor.tree.views().find_map(|(view, _focus)| {
-                view.pos_at_screen_coords(
-                    &editor.documents[&view.doc],
-                    row,
-                    column,
-                    ignore_virtual_text,
-                )
-                .map(|pos| (pos, view.id))
-            })
-        };
-
-        let gutter_coords_and_view = |editor: &Editor, row, column| {
-            editor.tree.views().find_map(|(view, _focus)| {
-                view.gutter_coords_at_screen_coords(row, column)
-                    .map(|coords| (coords, view.id))
-            })
-        };
-
         match kind {
             MouseEventKind::Down(MouseButton::Left) => {
                 let editor = &mut cxt.editor;
 
                 if let Some((pos, view_id)) = pos_and_view(editor, row, column, true) {
+                    if modifiers.contains(KeyModifiers::CONTROL) {
+                        let doc_id = view!(editor, view_id).doc;
+                        if let Some(link_idx) = document_link_at(&editor.documents[&doc_id], pos) {
+                            commands::open_document_link(editor, cxt.jobs, doc_id, link_idx);
+                            return EventResult::Consumed(None);
+                        }
+                    }
+
                     let prev_view_id = view!(editor).id;
                     let doc = doc_mut!(editor, &view!(editor, view_id).doc);
 
@@ -1528,29 +1614,13 @@ impl EditorView {
             }
 
             MouseEventKind::Up(MouseButton::Right) => {
-                if let Some((pos, view_id)) = gutter_coords_and_view(cxt.editor, row, column) {
-                    cxt.editor.focus(view_id);
-
-                    if let Some((pos, _)) = pos_and_view(cxt.editor, row, column, true) {
-                        doc_mut!(cxt.editor).set_selection(view_id, Selection::point(pos));
-                    } else {
-                        let (view, doc) = current!(cxt.editor);
-
-                        if let Some(pos) = view.pos_at_visual_coords(doc, pos.row as u16, 0, true) {
-                            doc.set_selection(view_id, Selection::point(pos));
-                            match modifiers {
-                                KeyModifiers::ALT => {
-                                    commands::MappableCommand::dap_edit_log.execute(cxt)
-                                }
-                                _ => commands::MappableCommand::dap_edit_condition.execute(cxt),
-                            };
-                        }
+                match MouseContextMenu::new(cxt.editor, *event) {
+                    Some(menu) => {
+                        cxt.push_layer(Box::new(menu));
+                        EventResult::Consumed(None)
                     }
-
-                    cxt.editor.ensure_cursor_in_view(view_id);
-                    return EventResult::Consumed(None);
+                    None => EventResult::Ignored(None),
                 }
-                EventResult::Ignored(None)
             }
 
             MouseEventKind::Up(MouseButton::Middle) => {
@@ -1578,6 +1648,17 @@ impl EditorView {
                 EventResult::Ignored(None)
             }
 
+            MouseEventKind::Moved => {
+                let editor = &mut cxt.editor;
+                let hovered = pos_and_view(editor, row, column, true).and_then(|(pos, view_id)| {
+                    let doc_id = view!(editor, view_id).doc;
+                    document_link_at(&editor.documents[&doc_id], pos).map(|idx| (doc_id, idx))
+                });
+                editor.hovered_document_link = hovered;
+
+                EventResult::Ignored(None)
+            }
+
             _ => EventResult::Ignored(None),
         }
     }
@@ -191,7 +191,7 @@ impl GradientBorder {
                 horizontal
             };
 
-            if let Some(cell) = surface.get_mut(x, area.top()) {
+            if let Some(cell) = surface.cell_mut((x, area.top())) {
                 cell.set_symbol(symbol).set_style(style);
             }
         }
@@ -209,7 +209,7 @@ impl GradientBorder {
                 horizontal
             };
 
-            if let Some(cell) = surface.get_mut(x, bottom_y) {
+            if let Some(cell) = surface.cell_mut((x, bottom_y)) {
                 cell.set_symbol(symbol).set_style(style);
             }
         }
@@ -219,7 +219,7 @@ impl GradientBorder {
             // Left border
             let color = self.get_gradient_color(area.left(), y, area);
             let style = Style::default().fg(color);
-            if let Some(cell) = surface.get_mut(area.left(), y) {
+            if let Some(cell) = surface.cell_mut((area.left(), y)) {
                 cell.set_symbol(vertical).set_style(style);
             }
 
@@ -227,7 +227,7 @@ impl GradientBorder {
             let right_x = area.right() - 1;
             let color = self.get_gradient_color(right_x, y, area);
             let style = Style::default().fg(color);
-            if let Some(cell) = surface.get_mut(right_x, y) {
+            if let Some(cell) = surface.cell_mut((right_x, y)) {
                 cell.set_symbol(vertical).set_style(style);
             }
         }
@@ -252,7 +252,7 @@ impl GradientBorder {
 
                 // Clear the area for the title and render it
                 for (i, ch) in title.chars().enumerate() {
-                    if let Some(cell) = surface.get_mut(title_start + i as u16, area.top()) {
+                    if let Some(cell) = surface.cell_mut((title_start + i as u16, area.top())) {
                         cell.set_char(ch).set_style(title_style);
                     }
                 }
@@ -92,7 +92,7 @@ impl Component for Hover {
             let sep_style = Style::default();
             let borders = BorderType::line_symbols(BorderType::Plain);
             for x in area.left()..area.right() {
-                if let Some(cell) = surface.get_mut(x, area.top() + HEADER_HEIGHT) {
+                if let Some(cell) = surface.cell_mut((x, area.top() + HEADER_HEIGHT)) {
                     cell.set_symbol(borders.horizontal).set_style(sep_style);
                 }
             }
@@ -5,6 +5,7 @@ mod info;
 pub mod lsp;
 mod markdown;
 pub mod menu;
+mod mouse_context_menu;
 pub mod overlay;
 pub mod picker;
 pub mod popup;
@@ -13,6 +14,7 @@ mod spinner;
 mod statusline;
 mod text;
 mod text_decorations;
+mod trust_prompt;
 
 use crate::compositor::Compositor;
 use crate::filter_picker_entry;
@@ -28,6 +30,7 @@ pub use picker::{Column as PickerColumn, FileLocation, Picker};
 pub use popup::Popup;
 pub use prompt::{Prompt, PromptEvent};
 pub use spinner::{ProgressSpinners, Spinner};
+pub use trust_prompt::TrustPrompt;
 pub use text::Text;
 
 use helix_view::Editor;
@@ -431,7 +434,7 @@ pub mod completers {
 
         fuzzy_match(input, names, true)
             .into_iter()
-            .map(|(name, _)| ((0..), name.into()))
+            .map(|(name, _)| ((0..), name.into(), None))
             .collect()
     }
 
@@ -447,7 +450,7 @@ pub mod completers {
 
         fuzzy_match(input, names, false)
             .into_iter()
-            .map(|(name, _)| ((0..), name.into()))
+            .map(|(name, _)| ((0..), name.into(), None))
             .collect()
     }
 
@@ -473,7 +476,7 @@ pub mod completers {
 
         fuzzy_match(input, language_servers, false)
             .into_iter()
-            .map(|(name, _)| ((0..), Span::raw(name.to_string())))
+            .map(|(name, _)| ((0..), Span::raw(name.to_string()), None))
             .collect()
     }
 
@@ -488,7 +491,7 @@ pub mod completers {
 
         fuzzy_match(input, language_servers, false)
             .into_iter()
-            .map(|(name, _)| ((0..), Span::raw(name.to_string())))
+            .map(|(name, _)| ((0..), Span::raw(name.to_string()), None))
             .collect()
     }
 
@@ -502,7 +505,7 @@ pub mod completers {
 
         fuzzy_match(input, &*KEYS, false)
             .into_iter()
-            .map(|(name, _)| ((0..), Span::raw(name)))
+            .map(|(name, _)| ((0..), Span::raw(name), None))
             .collect()
     }
 
@@ -537,7 +540,7 @@ pub mod completers {
 
         fuzzy_match(input, language_ids, false)
             .into_iter()
-            .map(|(name, _)| ((0..), name.to_owned().into()))
+            .map(|(name, _)| ((0..), name.to_owned().into(), None))
             .collect()
     }
 
@@ -553,7 +556,7 @@ pub mod completers {
 
         fuzzy_match(input, commands, false)
             .into_iter()
-            .map(|(name, _)| ((0..), name.to_owned().into()))
+            .map(|(name, _)| ((0..), name.to_owned().into(), None))
             .collect()
     }
 
@@ -687,15 +690,15 @@ pub mod completers {
             let range = (input.len().saturating_sub(file_name.len()))..;
             fuzzy_match(&file_name, files, true)
                 .into_iter()
-                .map(|(name, _)| (range.clone(), style_from_file(name)))
+                .map(|(name, _)| (range.clone(), style_from_file(name), None))
                 .collect()
 
             // TODO: complete to longest common match
         } else {
             let mut files: Vec<_> = files
-                .map(|file| (end.clone(), style_from_file(file)))
+                .map(|file| (end.clone(), style_from_file(file), None))
                 .collect();
-            files.sort_unstable_by(|(_, path1), (_, path2)| path1.content.cmp(&path2.content));
+            files.sort_unstable_by(|(_, path1, _), (_, path2, _)| path1.content.cmp(&path2.content));
             files
         }
     }
@@ -710,7 +713,7 @@ pub mod completers {
 
         fuzzy_match(input, iter, false)
             .into_iter()
-            .map(|(name, _)| ((0..), name.into()))
+            .map(|(name, _)| ((0..), name.into(), None))
             .collect()
     }
 
@@ -737,7 +740,7 @@ pub mod completers {
 
         fuzzy_match(input, PROGRAMS_IN_PATH.iter(), false)
             .into_iter()
-            .map(|(name, _)| ((0..), name.clone().into()))
+            .map(|(name, _)| ((0..), name.clone().into(), None))
             .collect()
     }
 
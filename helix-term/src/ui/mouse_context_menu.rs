@@ -0,0 +1,218 @@
+//! Right-click context menu opened over the buffer or the gutter.
+
+use crate::{
+    commands::{self, mouse::handle_selection_in_buffer, mouse::StaticMouseCommand, MappableCommand},
+    compositor::{Component, Compositor, Context, Event, EventResult},
+    ui::editor::{gutter_coords_and_view, pos_and_view},
+    ui::EditorView,
+};
+use helix_core::Selection;
+use helix_view::{
+    graphics::Rect,
+    input::{MouseEvent, MouseEventKind},
+    Editor,
+};
+use tui::buffer::Buffer as Surface;
+use tui::text::Text;
+use tui::widgets::{Block, Borders, Paragraph, Widget};
+
+/// A single entry in the context menu.
+#[derive(Clone)]
+enum ContextMenuAction {
+    Command(MappableCommand),
+    MouseCommand(StaticMouseCommand),
+}
+
+impl ContextMenuAction {
+    fn label(&self) -> &str {
+        match self {
+            Self::Command(cmd) => cmd.doc(),
+            Self::MouseCommand(cmd) => cmd.doc(),
+        }
+    }
+}
+
+/// A context menu that opens at the position of a right-click, offering commands appropriate to
+/// whichever part of the view (buffer text or gutter) was clicked.
+pub struct MouseContextMenu {
+    click: MouseEvent,
+    actions: Vec<ContextMenuAction>,
+    selected: usize,
+}
+
+impl MouseContextMenu {
+    pub const ID: &'static str = "mouse-context-menu";
+
+    /// Builds a context menu for a click at `click`, or `None` if the click did not land on any
+    /// view (e.g. it fell on the statusline).
+    pub fn new(editor: &Editor, click: MouseEvent) -> Option<Self> {
+        let actions = if gutter_coords_and_view(editor, click.row, click.column).is_some() {
+            vec![
+                ContextMenuAction::MouseCommand(StaticMouseCommand::add_breakpoint_mouse),
+                ContextMenuAction::MouseCommand(StaticMouseCommand::dap_edit_condition_mouse),
+                ContextMenuAction::MouseCommand(StaticMouseCommand::dap_edit_log_mouse),
+            ]
+        } else if pos_and_view(editor, click.row, click.column, true).is_some() {
+            vec![
+                ContextMenuAction::Command(MappableCommand::delete_selection),
+                ContextMenuAction::Command(MappableCommand::yank_main_selection_to_clipboard),
+                ContextMenuAction::Command(MappableCommand::paste_clipboard_after),
+                ContextMenuAction::Command(MappableCommand::goto_definition),
+                ContextMenuAction::Command(MappableCommand::goto_reference),
+                ContextMenuAction::Command(MappableCommand::rename_symbol),
+            ]
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            click,
+            actions,
+            selected: 0,
+        })
+    }
+
+    fn area(&self, viewport: Rect) -> Rect {
+        let width = self
+            .actions
+            .iter()
+            .map(|action| action.label().len() as u16 + 2)
+            .max()
+            .unwrap_or(0)
+            .max(4)
+            .min(viewport.width);
+        let height = (self.actions.len() as u16).min(viewport.height);
+
+        let x = self.click.column.min(viewport.width.saturating_sub(width));
+        let y = self.click.row.min(viewport.height.saturating_sub(height));
+
+        Rect::new(x, y, width, height)
+    }
+
+    fn close_and_execute(&mut self, action: Option<ContextMenuAction>) -> EventResult {
+        let click = self.click;
+        EventResult::Consumed(Some(Box::new(move |compositor: &mut Compositor, cx: &mut Context| {
+            compositor.remove(MouseContextMenu::ID);
+
+            let Some(action) = action else { return };
+            let Some(editor_view) = compositor.find::<EditorView>() else {
+                return;
+            };
+
+            let mut ctx = commands::Context {
+                register: None,
+                count: None,
+                editor: cx.editor,
+                callback: None,
+                on_next_key_callback: None,
+                jobs: cx.jobs,
+            };
+
+            handle_selection_in_buffer(&mut ctx, &click, editor_view, |doc, view_id, pos| {
+                doc.set_selection(*view_id, Selection::point(pos));
+            });
+
+            match action {
+                ContextMenuAction::Command(command) => command.execute(&mut ctx),
+                ContextMenuAction::MouseCommand(command) => {
+                    command.execute(&mut ctx, &click, editor_view)
+                }
+            }
+        })))
+    }
+}
+
+impl Component for MouseContextMenu {
+    fn render(&mut self, viewport: Rect, surface: &mut Surface, cx: &mut Context) {
+        let area = self.area(viewport);
+
+        let theme = &cx.editor.theme;
+        let popup_style = theme
+            .try_get("ui.popup")
+            .unwrap_or_else(|| theme.get("ui.text"));
+        let selected_style = theme
+            .try_get("ui.menu.selected")
+            .unwrap_or_else(|| theme.get("ui.selection"));
+
+        for row in area.top()..area.bottom() {
+            for col in area.left()..area.right() {
+                if let Some(cell) = surface.cell_mut((col, row)) {
+                    cell.set_style(popup_style);
+                    cell.set_char(' ');
+                }
+            }
+        }
+
+        let block = Block::default().borders(Borders::ALL).border_style(popup_style);
+        let inner = block.inner(area);
+        block.render(area, surface);
+
+        for (i, action) in self.actions.iter().enumerate() {
+            let Some(row) = inner.top().checked_add(i as u16) else {
+                break;
+            };
+            if row >= inner.bottom() {
+                break;
+            }
+            let style = if i == self.selected {
+                selected_style
+            } else {
+                popup_style
+            };
+            let text = Text::styled(action.label(), style);
+            Paragraph::new(&text).render(Rect::new(inner.x, row, inner.width, 1), surface);
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, cx: &mut Context) -> EventResult {
+        let viewport = cx.editor.tree.area();
+        let area = self.area(viewport);
+
+        match event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(_),
+                row,
+                column,
+                ..
+            }) => {
+                let in_area = (area.left()..area.right()).contains(column)
+                    && (area.top()..area.bottom()).contains(row);
+                if in_area {
+                    let inner_top = area.top() + 1;
+                    let index = (*row).saturating_sub(inner_top) as usize;
+                    if index < self.actions.len() {
+                        let action = self.actions[index].clone();
+                        return self.close_and_execute(Some(action));
+                    }
+                    EventResult::Consumed(None)
+                } else {
+                    // Clicked outside of the menu: dismiss without executing anything.
+                    self.close_and_execute(None)
+                }
+            }
+
+            Event::Key(key) => match key.code {
+                helix_view::keyboard::KeyCode::Up => {
+                    self.selected = self.selected.saturating_sub(1);
+                    EventResult::Consumed(None)
+                }
+                helix_view::keyboard::KeyCode::Down => {
+                    self.selected = (self.selected + 1).min(self.actions.len().saturating_sub(1));
+                    EventResult::Consumed(None)
+                }
+                helix_view::keyboard::KeyCode::Enter => {
+                    let action = self.actions.get(self.selected).cloned();
+                    self.close_and_execute(action)
+                }
+                helix_view::keyboard::KeyCode::Esc => self.close_and_execute(None),
+                _ => EventResult::Consumed(None),
+            },
+
+            _ => EventResult::Ignored(None),
+        }
+    }
+
+    fn id(&self) -> Option<&'static str> {
+        Some(Self::ID)
+    }
+}
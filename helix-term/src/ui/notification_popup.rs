@@ -403,13 +403,13 @@ impl NotificationPopup {
             for x in x0..=x1 {
                 let ch_top = if x == x0 { tl } else if x == x1 { tr } else { h };
                 let ch_bot = if x == x0 { bl } else if x == x1 { br } else { h };
-                if let Some(cell) = surface.get_mut(x, y0) { cell.set_symbol(ch_top).set_style(style); }
-                if let Some(cell) = surface.get_mut(x, y1) { cell.set_symbol(ch_bot).set_style(style); }
+                if let Some(cell) = surface.cell_mut((x, y0)) { cell.set_symbol(ch_top).set_style(style); }
+                if let Some(cell) = surface.cell_mut((x, y1)) { cell.set_symbol(ch_bot).set_style(style); }
             }
             // Left and right lines
             for y in (y0+1)..y1 {
-                if let Some(cell) = surface.get_mut(x0, y) { cell.set_symbol(v).set_style(style); }
-                if let Some(cell) = surface.get_mut(x1, y) { cell.set_symbol(v).set_style(style); }
+                if let Some(cell) = surface.cell_mut((x0, y)) { cell.set_symbol(v).set_style(style); }
+                if let Some(cell) = surface.cell_mut((x1, y)) { cell.set_symbol(v).set_style(style); }
             }
         }
     }
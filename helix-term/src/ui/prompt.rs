@@ -23,7 +23,10 @@ use helix_view::{
 
 type PromptCharHandler = Box<dyn Fn(&mut Prompt, char, &Context)>;
 
-pub type Completion = (RangeFrom<usize>, Span<'static>);
+/// Documentation shown for a completion candidate, e.g. a command's signature and description.
+pub type CompletionDoc = Cow<'static, str>;
+
+pub type Completion = (RangeFrom<usize>, Span<'static>, Option<CompletionDoc>);
 type CompletionFn = Box<dyn FnMut(&Editor, &str) -> Vec<Completion>>;
 type CallbackFn = Box<dyn FnMut(&mut Context, &str, PromptEvent)>;
 pub type DocFn = Box<dyn Fn(&str) -> Option<Cow<str>>>;
@@ -40,6 +43,9 @@ pub struct Prompt {
     // ---
     completion: Vec<Completion>,
     selection: Option<usize>,
+    /// Documentation for the currently selected completion candidate, shown in a preview box
+    /// next to the completion list as the selection moves via Tab/Shift-Tab.
+    completion_doc: Option<CompletionDoc>,
     history_register: Option<char>,
     history_pos: Option<usize>,
     completion_fn: CompletionFn,
@@ -96,6 +102,7 @@ impl Prompt {
             truncate_end: false,
             completion: Vec::new(),
             selection: None,
+            completion_doc: None,
             history_register,
             history_pos: None,
             completion_fn: Box::new(completion_fn),
@@ -386,15 +393,17 @@ impl Prompt {
 
         self.selection = Some(index);
 
-        let (range, item) = &self.completion[index];
+        let (range, item, doc) = &self.completion[index];
 
         self.line.replace_range(range.clone(), &item.content);
+        self.completion_doc = doc.clone();
 
         self.move_end();
     }
 
     pub fn exit_selection(&mut self) {
         self.selection = None;
+        self.completion_doc = None;
     }
 }
 
@@ -413,7 +422,7 @@ impl Prompt {
         let max_len = self
             .completion
             .iter()
-            .map(|(_, completion)| completion.content.len() as u16)
+            .map(|(_, completion, _)| completion.content.len() as u16)
             .max()
             .unwrap_or(BASE_WIDTH)
             .max(BASE_WIDTH);
@@ -449,7 +458,7 @@ impl Prompt {
             let mut row = 0;
             let mut col = 0;
 
-            for (i, (_range, completion)) in
+            for (i, (_range, completion, _doc)) in
                 self.completion.iter().enumerate().skip(offset).take(items)
             {
                 let is_selected = Some(i) == self.selection;
@@ -476,7 +485,12 @@ impl Prompt {
             }
         }
 
-        if let Some(doc) = (self.doc_fn)(&self.line) {
+        let doc = match &self.completion_doc {
+            Some(doc) => Some(doc.clone()),
+            None => (self.doc_fn)(&self.line).map(|doc| Cow::Owned(doc.into_owned())),
+        };
+
+        if let Some(doc) = doc {
             let mut text = ui::Text::new(doc.to_string());
 
             let max_width = BASE_WIDTH * 3;
@@ -730,7 +744,7 @@ impl Component for Prompt {
                     .editor
                     .registers
                     .iter_preview()
-                    .map(|(ch, preview)| (0.., format!("{} {}", ch, &preview).into()))
+                    .map(|(ch, preview)| (0.., format!("{} {}", ch, &preview).into(), None))
                     .collect();
                 self.next_char_handler = Some(Box::new(|prompt, c, context| {
                     prompt.insert_str(
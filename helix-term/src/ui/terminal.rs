@@ -52,7 +52,7 @@ impl Terminal {
             surface.clear(area);
 
             for cell in content.display_iter {
-                if let Some(c) = surface.get_mut(
+                if let Some(c) = surface.cell_mut((
                     area.left() + cell.point.column.0 as u16,
                     area.top()
                         + (cell
@@ -61,7 +61,7 @@ impl Terminal {
                             .0
                             .saturating_add(content.display_offset as i32))
                             as u16,
-                ) {
+                )) {
                     let style = helix_view::theme::Style::reset()
                         .bg(helix_view::terminal::color_from_ansi(cell.bg))
                         .fg(helix_view::terminal::color_from_ansi(cell.fg))
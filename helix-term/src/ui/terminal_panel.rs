@@ -278,7 +278,7 @@ impl TerminalPanel {
 
         // Clear the tab bar area with background
         for x in area.x..area.x + area.width {
-            if let Some(cell) = surface.get_mut(x, area.y) {
+            if let Some(cell) = surface.cell_mut((x, area.y)) {
                 cell.reset();
                 cell.set_style(background_style);
             }
@@ -313,7 +313,7 @@ impl TerminalPanel {
                 if x_offset >= area.x + area.width {
                     break;
                 }
-                if let Some(cell) = surface.get_mut(x_offset, area.y) {
+                if let Some(cell) = surface.cell_mut((x_offset, area.y)) {
                     cell.set_char(c);
                     cell.set_style(style);
                 }
@@ -325,7 +325,7 @@ impl TerminalPanel {
 
             // Draw separator between tabs
             if i < self.terminals.len() - 1 && x_offset < area.x + area.width {
-                if let Some(cell) = surface.get_mut(x_offset, area.y) {
+                if let Some(cell) = surface.cell_mut((x_offset, area.y)) {
                     cell.set_char('│');
                     cell.set_style(background_style);
                 }
@@ -457,7 +457,7 @@ impl Component for TerminalPanel {
         // Draw top border/separator
         let separator_y = area.y;
         for x in area.x..area.x + area.width {
-            if let Some(cell) = surface.get_mut(x, separator_y) {
+            if let Some(cell) = surface.cell_mut((x, separator_y)) {
                 cell.set_symbol("─");
                 cell.set_style(border_style);
             }
@@ -486,7 +486,7 @@ impl Component for TerminalPanel {
             let y = content_area.y + content_area.height / 2;
 
             for (i, c) in msg.chars().enumerate() {
-                if let Some(cell) = surface.get_mut(x + i as u16, y) {
+                if let Some(cell) = surface.cell_mut((x + i as u16, y)) {
                     cell.set_char(c);
                     cell.set_style(empty_style);
                 }
@@ -173,7 +173,7 @@ impl TerminalView {
         // Clear the area first
         for y in area.y..area.y + area.height {
             for x in area.x..area.x + area.width {
-                if let Some(cell) = surface.get_mut(x, y) {
+                if let Some(cell) = surface.cell_mut((x, y)) {
                     cell.reset();
                     cell.set_style(theme_bg);
                 }
@@ -187,7 +187,7 @@ impl TerminalView {
                     let x = area.x + col;
                     let y = area.y + row;
 
-                    if let Some(surface_cell) = surface.get_mut(x, y) {
+                    if let Some(surface_cell) = surface.cell_mut((x, y)) {
                         let mut style = Style::default();
 
                         // Apply foreground color
@@ -1,28 +1,28 @@
-//! Trust prompt dialog for workspace trust decisions.
+//! Trust prompt dialog for per-capability workspace trust decisions.
 
 use crate::compositor::{Component, Compositor, Context, Event, EventResult};
 use helix_view::graphics::Rect;
 use helix_view::input::KeyEvent;
 use helix_view::keyboard::{KeyCode, KeyModifiers};
+use helix_view::trust::{self, TrustCapability, TrustDecision};
 use std::path::PathBuf;
 use tui::buffer::Buffer as Surface;
 use tui::text::Text;
 use tui::widgets::{Block, Borders, Paragraph, Widget};
 
-/// Decision made by the user in the trust prompt.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum TrustDecision {
-    /// User chose to trust the workspace (y/Y).
-    Trust,
-    /// User chose not to trust the workspace (n/N).
-    Untrust,
-    /// User cancelled without making a decision (Esc).
-    Cancel,
-}
+const ITEMS: &[(TrustCapability, &str)] = &[
+    (TrustCapability::LSP, "Language servers (LSP)"),
+    (TrustCapability::SHELL, "Shell commands"),
+    (TrustCapability::CONFIG, "Workspace configuration"),
+];
 
-/// A prompt dialog asking the user whether to trust a workspace.
+/// A prompt dialog asking the user which capabilities to trust a workspace with.
 pub struct TrustPrompt {
     workspace_path: PathBuf,
+    /// Capabilities checked so far; toggled with Space, confirmed with `y`.
+    pending: TrustCapability,
+    /// Index of the checkbox the cursor is currently on.
+    selected: usize,
     callback: Option<Box<dyn FnOnce(&mut Compositor, &mut helix_view::Editor, TrustDecision) + Send>>,
 }
 
@@ -33,16 +33,22 @@ impl TrustPrompt {
     ) -> Self {
         Self {
             workspace_path,
+            // Default to trusting everything, the common case of a workspace the user opened
+            // on purpose; the checkboxes let them narrow it down before confirming.
+            pending: TrustCapability::all(),
+            selected: 0,
             callback: Some(Box::new(callback)),
         }
     }
 
     fn close_with_decision(&mut self, decision: TrustDecision) -> EventResult {
+        if let Some(capabilities) = decision {
+            trust::trust(&self.workspace_path, capabilities);
+        }
+
         let callback = self.callback.take();
         EventResult::Consumed(Some(Box::new(move |compositor: &mut Compositor, cx: &mut Context| {
-            // Remove the prompt from the compositor
             compositor.remove(TrustPrompt::ID);
-            // Call the callback with the decision
             if let Some(cb) = callback {
                 cb(compositor, cx.editor, decision);
             }
@@ -72,16 +78,19 @@ impl Component for TrustPrompt {
             path_display
         };
 
-        let text = Text::from(format!(
-            "Do you trust the authors of this workspace?\n\n\
-             {}\n\n\
-             Trusting enables:\n\
-              - Language servers (LSP)\n\
-              - Shell commands\n\
-              - Workspace configuration\n\n\
-             [y] Trust   [n] Don't Trust   [Esc] Cancel",
-            path_str
-        ));
+        let mut body = format!("Do you trust the authors of this workspace?\n\n{}\n\n", path_str);
+        for (i, (capability, label)) in ITEMS.iter().enumerate() {
+            let checkbox = if self.pending.contains(*capability) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            let cursor = if i == self.selected { ">" } else { " " };
+            body.push_str(&format!("{cursor} {checkbox} {label}\n"));
+        }
+        body.push_str("\n[Space] Toggle  [y] Confirm  [Esc] Cancel");
+
+        let text = Text::from(body);
 
         let theme = &cx.editor.theme;
         let style = theme
@@ -91,7 +100,7 @@ impl Component for TrustPrompt {
         // Clear the dialog area with the background style
         for row in dialog_area.top()..dialog_area.bottom() {
             for col in dialog_area.left()..dialog_area.right() {
-                if let Some(cell) = surface.get_mut(col, row) {
+                if let Some(cell) = surface.cell_mut((col, row)) {
                     cell.set_style(style);
                     cell.set_char(' ');
                 }
@@ -113,17 +122,36 @@ impl Component for TrustPrompt {
             Event::Key(KeyEvent {
                 code: KeyCode::Char('y' | 'Y'),
                 modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
-            }) => self.close_with_decision(TrustDecision::Trust),
+            }) => self.close_with_decision(Some(self.pending)),
 
             Event::Key(KeyEvent {
-                code: KeyCode::Char('n' | 'N'),
-                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
-            }) => self.close_with_decision(TrustDecision::Untrust),
+                code: KeyCode::Esc, ..
+            }) => self.close_with_decision(None),
 
             Event::Key(KeyEvent {
-                code: KeyCode::Esc,
-                ..
-            }) => self.close_with_decision(TrustDecision::Cancel),
+                code: KeyCode::Char(' '),
+                modifiers: KeyModifiers::NONE,
+            }) => {
+                let (capability, _) = ITEMS[self.selected];
+                self.pending.toggle(capability);
+                EventResult::Consumed(None)
+            }
+
+            Event::Key(KeyEvent {
+                code: KeyCode::Up | KeyCode::Char('k'),
+                modifiers: KeyModifiers::NONE,
+            }) => {
+                self.selected = self.selected.checked_sub(1).unwrap_or(ITEMS.len() - 1);
+                EventResult::Consumed(None)
+            }
+
+            Event::Key(KeyEvent {
+                code: KeyCode::Down | KeyCode::Char('j'),
+                modifiers: KeyModifiers::NONE,
+            }) => {
+                self.selected = (self.selected + 1) % ITEMS.len();
+                EventResult::Consumed(None)
+            }
 
             // Consume all other events to prevent them from reaching the editor
             Event::Key(_) => EventResult::Consumed(None),
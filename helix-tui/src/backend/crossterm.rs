@@ -31,6 +31,36 @@ fn vte_version() -> Option<usize> {
     std::env::var("VTE_VERSION").ok()?.parse().ok()
 }
 
+/// Whether the terminal advertises 24-bit ("true") color support, via either the user's config,
+/// `$COLORTERM`, or the terminfo database.
+fn supports_true_color(config: &EditorConfig) -> bool {
+    if config.true_color {
+        return true;
+    }
+
+    if matches!(
+        std::env::var("COLORTERM").map(|v| matches!(v.as_str(), "truecolor" | "24bit")),
+        Ok(true)
+    ) {
+        return true;
+    }
+
+    match termini::TermInfo::from_env() {
+        Ok(t) => {
+            t.extended_cap("RGB").is_some()
+                || t.extended_cap("Tc").is_some()
+                || (t.extended_cap("setrgbf").is_some() && t.extended_cap("setrgbb").is_some())
+        }
+        Err(_) => false,
+    }
+}
+
+/// Whether the terminal advertises at least an xterm-256 palette, via `$TERM`/`$COLORTERM`.
+fn supports_256_color() -> bool {
+    matches!(std::env::var("TERM"), Ok(term) if term.contains("256color"))
+        || std::env::var("COLORTERM").is_ok()
+}
+
 /// Describes terminal capabilities like extended underline, truecolor, etc.
 #[derive(Clone, Debug)]
 struct Capabilities {
@@ -38,6 +68,10 @@ struct Capabilities {
     has_extended_underlines: bool,
     /// Support for resetting the cursor style back to normal.
     reset_cursor_command: String,
+    /// `true` when the terminal is believed to support only the named 16-color ANSI palette, so
+    /// `Color::Rgb`/`Color::Indexed` cells must be downgraded to the nearest named color before
+    /// being written, instead of emitting 24-bit/256-color SGR sequences it can't render.
+    downgrade_to_16_colors: bool,
 }
 
 impl Default for Capabilities {
@@ -45,6 +79,7 @@ impl Default for Capabilities {
         Self {
             has_extended_underlines: false,
             reset_cursor_command: "\x1B[0 q".to_string(),
+            downgrade_to_16_colors: false,
         }
     }
 }
@@ -54,8 +89,13 @@ impl Capabilities {
     /// on the $TERM environment variable. If detection fails, returns
     /// a default value where no capability is supported.
     pub fn from_env_or_default(config: &EditorConfig) -> Self {
+        let downgrade_to_16_colors = !supports_true_color(config) && !supports_256_color();
+
         match termini::TermInfo::from_env() {
-            Err(_) => Capabilities::default(),
+            Err(_) => Capabilities {
+                downgrade_to_16_colors,
+                ..Capabilities::default()
+            },
             Ok(t) => Capabilities {
                 // Smulx, VTE: https://unix.stackexchange.com/a/696253/246284
                 // Su (used by kitty): https://sw.kovidgoyal.net/kitty/underlines
@@ -69,6 +109,7 @@ impl Capabilities {
                     .utf8_string_cap(termini::StringCapability::CursorNormal)
                     .unwrap_or("\x1B[0 q")
                     .to_string(),
+                downgrade_to_16_colors,
             },
         }
     }
@@ -96,6 +137,17 @@ where
         }
     }
 
+    /// Downgrades `color` to the nearest named 16-color ANSI color when the terminal doesn't
+    /// advertise support for anything richer, leaving it untouched otherwise.
+    #[inline]
+    fn downgrade_color(&self, color: Color) -> Color {
+        if self.capabilities.downgrade_to_16_colors {
+            color.to_16_color()
+        } else {
+            color
+        }
+    }
+
     #[inline]
     fn supports_keyboard_enhancement_protocol(&self) -> bool {
         *self.supports_keyboard_enhancement_protocol
@@ -236,12 +288,12 @@ where
                 modifier = cell.modifier;
             }
             if cell.fg != fg {
-                let color = CColor::from(cell.fg);
+                let color = CColor::from(self.downgrade_color(cell.fg));
                 queue!(self.buffer, SetForegroundColor(color))?;
                 fg = cell.fg;
             }
             if cell.bg != bg {
-                let color = CColor::from(cell.bg);
+                let color = CColor::from(self.downgrade_color(cell.bg));
                 queue!(self.buffer, SetBackgroundColor(color))?;
                 bg = cell.bg;
             }
@@ -249,7 +301,7 @@ where
             let mut new_underline_style = cell.underline_style;
             if self.capabilities.has_extended_underlines {
                 if cell.underline_color != underline_color {
-                    let color = CColor::from(cell.underline_color);
+                    let color = CColor::from(self.downgrade_color(cell.underline_color));
                     queue!(self.buffer, SetUnderlineColor(color))?;
                     underline_color = cell.underline_color;
                 }
@@ -5,6 +5,34 @@ use unicode_segmentation::UnicodeSegmentation;
 
 use helix_view::graphics::{Color, Modifier, Rect, Style, UnderlineStyle};
 
+/// A (x, y) coordinate pair locating a [`Cell`] within a [`Buffer`].
+///
+/// Unlike [`helix_core::Position`], which addresses a location within a document, this addresses
+/// a screen cell and uses `(x, y)` ordering to match [`Rect`]'s fields.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub x: u16,
+    pub y: u16,
+}
+
+impl Position {
+    pub fn new(x: u16, y: u16) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<(u16, u16)> for Position {
+    fn from((x, y): (u16, u16)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Position> for (u16, u16) {
+    fn from(position: Position) -> Self {
+        (position.x, position.y)
+    }
+}
+
 /// A buffer cell
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Cell {
@@ -179,12 +207,28 @@ impl Buffer {
     }
 
     /// Returns a reference to Cell at the given coordinates
+    #[deprecated(note = "use `Buffer::cell` instead")]
     pub fn get(&self, x: u16, y: u16) -> Option<&Cell> {
-        self.index_of_opt(x, y).map(|i| &self.content[i])
+        self.cell((x, y))
     }
 
     /// Returns a mutable reference to Cell at the given coordinates
+    #[deprecated(note = "use `Buffer::cell_mut` instead")]
     pub fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut Cell> {
+        self.cell_mut((x, y))
+    }
+
+    /// Returns a reference to the `Cell` at the given position, or `None` if the position falls
+    /// outside of the buffer's area.
+    pub fn cell<P: Into<Position>>(&self, position: P) -> Option<&Cell> {
+        let Position { x, y } = position.into();
+        self.index_of_opt(x, y).map(|i| &self.content[i])
+    }
+
+    /// Returns a mutable reference to the `Cell` at the given position, or `None` if the position
+    /// falls outside of the buffer's area.
+    pub fn cell_mut<P: Into<Position>>(&mut self, position: P) -> Option<&mut Cell> {
+        let Position { x, y } = position.into();
         self.index_of_opt(x, y).map(|i| &mut self.content[i])
     }
 
@@ -660,6 +704,20 @@ impl std::ops::IndexMut<(u16, u16)> for Buffer {
     }
 }
 
+impl std::ops::Index<Position> for Buffer {
+    type Output = Cell;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        &self[(position.x, position.y)]
+    }
+}
+
+impl std::ops::IndexMut<Position> for Buffer {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        &mut self[(position.x, position.y)]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
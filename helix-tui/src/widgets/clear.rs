@@ -29,7 +29,9 @@ impl Widget for Clear {
     fn render(self, area: Rect, buf: &mut Buffer) {
         for x in area.left()..area.right() {
             for y in area.top()..area.bottom() {
-                buf.get_mut(x, y).reset();
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.reset();
+                }
             }
         }
     }
@@ -1,5 +1,6 @@
 use crate::input::{
     Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    MouseModifiers,
 };
 
 impl From<crossterm::event::Event> for Event {
@@ -26,6 +27,7 @@ impl From<crossterm::event::MouseEvent> for MouseEvent {
             column,
             row,
             modifiers: modifiers.into(),
+            mouse_modifiers: MouseModifiers::MultipleClick(1),
         }
     }
 }
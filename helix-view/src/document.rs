@@ -213,6 +213,11 @@ pub struct Document {
     pub color_swatch_controller: TaskController,
     pub pull_diagnostic_controller: TaskController,
 
+    /// Cached LSP document links, sorted by `start..end`, for navigation and rendering.
+    pub document_links: Vec<DocumentLink>,
+    // NOTE: like `color_swatch_controller`, this would ideally live on the handler instead.
+    pub document_link_controller: TaskController,
+
     // NOTE: this field should eventually go away - we should use the Editor's syn_loader instead
     // of storing a copy on every doc. Then we can remove the surrounding `Arc` and use the
     // `ArcSwap` directly.
@@ -226,6 +231,17 @@ pub struct DocumentColorSwatches {
     pub color_swatches_padding: Vec<InlineAnnotation>,
 }
 
+/// An LSP document link, translated into document char positions and cached for navigation,
+/// rendering, and click-to-follow. `link.target` may be `None` until resolved (see
+/// `documentLink/resolve`).
+#[derive(Debug, Clone)]
+pub struct DocumentLink {
+    pub start: usize,
+    pub end: usize,
+    pub link: lsp::DocumentLink,
+    pub language_server_id: LanguageServerId,
+}
+
 /// Inlay hints for a single `(Document, View)` combo.
 ///
 /// There are `*_inlay_hints` field for each kind of hints an LSP can send since we offer the
@@ -733,6 +749,8 @@ impl Document {
             syn_loader,
             previous_diagnostic_id: None,
             pull_diagnostic_controller: TaskController::new(),
+            document_links: Vec::new(),
+            document_link_controller: TaskController::new(),
         }
     }
 
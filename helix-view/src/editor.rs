@@ -310,6 +310,9 @@ pub struct Config {
     pub gutters: GutterConfig,
     /// Middle click paste support. Defaults to true.
     pub middle_click_paste: bool,
+    /// Maximum gap in milliseconds between clicks of the same mouse button/modifiers for them
+    /// to count as a double/triple click in `[mouse.<mode>]` bindings. Defaults to 300.
+    pub mouse_idle_timeout: u64,
     /// Automatic insertion of pairs to parentheses, brackets,
     /// etc. Optionally, this can be a list of 2-tuples to specify a
     /// global list of characters to pair. Defaults to true.
@@ -1098,6 +1101,7 @@ impl Default for Config {
             cursorcolumn: false,
             gutters: GutterConfig::default(),
             middle_click_paste: true,
+            mouse_idle_timeout: 300,
             auto_pairs: AutoPairConfig::default(),
             auto_completion: true,
             path_completion: true,
@@ -1249,7 +1253,13 @@ pub struct Editor {
     pub handlers: Handlers,
 
     pub mouse_down_range: Option<Range>,
+    /// Whether [`Editor::mouse_down_range`] should be grown line-by-line while dragging, rather
+    /// than character-by-character. Set by a triple click to start a line-select-and-drag.
+    pub mouse_line_select: bool,
     pub cursor_cache: CursorCache,
+    /// The document link currently under the mouse pointer, used to render a hover affordance
+    /// and to resolve ctrl/cmd-click navigation. Cleared whenever the pointer moves off a link.
+    pub hovered_document_link: Option<(DocumentId, usize)>,
 }
 
 pub type Motion = Box<dyn Fn(&mut Editor)>;
@@ -1370,7 +1380,9 @@ impl Editor {
             needs_redraw: false,
             handlers,
             mouse_down_range: None,
+            mouse_line_select: false,
             cursor_cache: CursorCache::default(),
+            hovered_document_link: None,
         }
     }
 
@@ -1627,6 +1639,10 @@ impl Editor {
         if !self.config().lsp.enable {
             return;
         }
+        let (workspace, _) = helix_loader::find_workspace();
+        if !crate::trust::is_allowed(&workspace, crate::trust::TrustCapability::LSP) {
+            return;
+        }
         // if doc doesn't have a URL it's a scratch buffer, ignore it
         let Some(doc) = self.documents.get_mut(&doc_id) else {
             return;
@@ -286,6 +286,93 @@ impl Color {
             _ => None,
         }
     }
+
+    /// Downgrades `Rgb`/`Indexed` colors to the nearest color in the named 16-color
+    /// ANSI palette, leaving already-named colors untouched.
+    ///
+    /// Useful for themes that want to look reasonable on terminals that only
+    /// advertise a 16-color palette instead of true color or xterm-256 support.
+    pub fn to_16_color(self) -> Self {
+        match self {
+            Color::Rgb(r, g, b) => Self::nearest_16_color(r, g, b),
+            Color::Indexed(i) => {
+                let (r, g, b) = indexed_to_rgb(i);
+                Self::nearest_16_color(r, g, b)
+            }
+            color => color,
+        }
+    }
+
+    fn nearest_16_color(r: u8, g: u8, b: u8) -> Self {
+        const PALETTE: [(Color, (u8, u8, u8)); 16] = [
+            (Color::Black, (0, 0, 0)),
+            (Color::Red, (128, 0, 0)),
+            (Color::Green, (0, 128, 0)),
+            (Color::Yellow, (128, 128, 0)),
+            (Color::Blue, (0, 0, 128)),
+            (Color::Magenta, (128, 0, 128)),
+            (Color::Cyan, (0, 128, 128)),
+            (Color::Gray, (128, 128, 128)),
+            (Color::LightRed, (255, 0, 0)),
+            (Color::LightGreen, (0, 255, 0)),
+            (Color::LightYellow, (255, 255, 0)),
+            (Color::LightBlue, (0, 0, 255)),
+            (Color::LightMagenta, (255, 0, 255)),
+            (Color::LightCyan, (0, 255, 255)),
+            (Color::LightGray, (192, 192, 192)),
+            (Color::White, (255, 255, 255)),
+        ];
+
+        PALETTE
+            .into_iter()
+            .min_by_key(|&(_, (pr, pg, pb))| {
+                let dr = i32::from(r) - i32::from(pr);
+                let dg = i32::from(g) - i32::from(pg);
+                let db = i32::from(b) - i32::from(pb);
+                dr * dr + dg * dg + db * db
+            })
+            .map(|(color, _)| color)
+            .expect("palette is non-empty")
+    }
+}
+
+/// Converts an xterm-256 palette index to its approximate RGB value.
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => {
+            const BASE: [(u8, u8, u8); 16] = [
+                (0, 0, 0),
+                (128, 0, 0),
+                (0, 128, 0),
+                (128, 128, 0),
+                (0, 0, 128),
+                (128, 0, 128),
+                (0, 128, 128),
+                (192, 192, 192),
+                (128, 128, 128),
+                (255, 0, 0),
+                (0, 255, 0),
+                (255, 255, 0),
+                (0, 0, 255),
+                (255, 0, 255),
+                (0, 255, 255),
+                (255, 255, 255),
+            ];
+            BASE[index as usize]
+        }
+        16..=231 => {
+            let i = index - 16;
+            let r = i / 36;
+            let g = (i % 36) / 6;
+            let b = i % 6;
+            const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+            (LEVELS[r as usize], LEVELS[g as usize], LEVELS[b as usize])
+        }
+        232..=255 => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
 }
 
 #[cfg(feature = "term")]
@@ -727,6 +814,28 @@ mod tests {
         ]
     }
 
+    #[test]
+    fn to_16_color_leaves_named_colors_untouched() {
+        assert_eq!(Color::Yellow.to_16_color(), Color::Yellow);
+        assert_eq!(Color::Reset.to_16_color(), Color::Reset);
+    }
+
+    #[test]
+    fn to_16_color_downgrades_rgb_to_nearest_named_color() {
+        assert_eq!(Color::Rgb(255, 0, 0).to_16_color(), Color::LightRed);
+        assert_eq!(Color::Rgb(1, 1, 1).to_16_color(), Color::Black);
+        assert_eq!(Color::Rgb(250, 250, 250).to_16_color(), Color::White);
+    }
+
+    #[test]
+    fn to_16_color_downgrades_indexed_to_nearest_named_color() {
+        // Indices 0..=15 map directly onto the named ANSI palette they mirror.
+        assert_eq!(Color::Indexed(1).to_16_color(), Color::Red);
+        // A pure-red cube entry from the 256-color palette downgrades the same way
+        // a true-color red would.
+        assert_eq!(Color::Indexed(196).to_16_color(), Color::LightRed);
+    }
+
     #[test]
     fn combined_patch_gives_same_result_as_individual_patch() {
         let styles = styles();
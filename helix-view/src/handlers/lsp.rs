@@ -17,6 +17,8 @@ use super::Handlers;
 
 pub struct DocumentColorsEvent(pub DocumentId);
 
+pub struct DocumentLinksEvent(pub DocumentId);
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SignatureHelpInvoked {
     Automatic,
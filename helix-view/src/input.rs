@@ -27,6 +27,36 @@ pub struct MouseEvent {
     pub row: u16,
     /// The key modifiers active when the event occurred.
     pub modifiers: KeyModifiers,
+    /// Disambiguates repeated clicks at the same spot (single/double/triple click) for
+    /// keymap lookup. Not populated by crossterm directly; maintained by `Mousemaps`.
+    pub mouse_modifiers: MouseModifiers,
+}
+
+impl MouseEvent {
+    /// Compares everything a mouse keymap cares about except on-screen position: the event
+    /// kind (which includes the button) and the key modifiers, but not [`Self::mouse_modifiers`]
+    /// or the coordinates. Used to detect whether a new `Down` event continues the same
+    /// button/modifier combo as the last one, so its click count can be incremented.
+    pub fn light_eq(&self, other: &MouseEvent) -> bool {
+        self.kind == other.kind && self.modifiers == other.modifiers
+    }
+
+    /// Returns a copy with `column`/`row` zeroed out, suitable for use as a mouse keymap lookup
+    /// key (bindings are not sensitive to exactly where on screen the click landed).
+    pub fn clone_without_coords(&self) -> MouseEvent {
+        MouseEvent {
+            column: 0,
+            row: 0,
+            ..*self
+        }
+    }
+}
+
+/// Disambiguates a single click from a double- or triple-click (etc.) of the same mouse
+/// button/modifier combo, for mouse keymap lookup. See [`MouseEvent::light_eq`].
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MouseModifiers {
+    MultipleClick(u8),
 }
 
 #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
@@ -448,7 +478,101 @@ impl From<crossterm::event::MouseEvent> for MouseEvent {
             column,
             row,
             modifiers: modifiers.into(),
+            mouse_modifiers: MouseModifiers::MultipleClick(1),
+        }
+    }
+}
+
+/// Parses mouse keymap bindings, e.g. `"1-left"` (single left click), `"2-left"` (double
+/// click), `"A-1-middle"` (alt + single middle click), `"scroll_up"`/`"scroll_down"`, or
+/// `"drag_left"` (dragging while holding the left button).
+/// Mirrors [`KeyEvent`]'s `S-`/`A-`/`C-` modifier prefixes; click count and button are always
+/// the final two `-`-separated tokens, except for the wheel directions and drag events, neither
+/// of which take a count.
+impl std::str::FromStr for MouseEvent {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let scroll_kind = match s {
+            "scroll_up" => Some(MouseEventKind::ScrollUp),
+            "scroll_down" => Some(MouseEventKind::ScrollDown),
+            "scroll_left" => Some(MouseEventKind::ScrollLeft),
+            "scroll_right" => Some(MouseEventKind::ScrollRight),
+            _ => None,
+        };
+        if let Some(kind) = scroll_kind {
+            return Ok(MouseEvent {
+                kind,
+                column: 0,
+                row: 0,
+                modifiers: KeyModifiers::empty(),
+                mouse_modifiers: MouseModifiers::MultipleClick(1),
+            });
+        }
+
+        if let Some(button) = s.strip_prefix("drag_") {
+            let button = match button {
+                "left" => MouseButton::Left,
+                "right" => MouseButton::Right,
+                "middle" => MouseButton::Middle,
+                invalid => return Err(anyhow!("Invalid mouse button '{}'", invalid)),
+            };
+            return Ok(MouseEvent {
+                kind: MouseEventKind::Drag(button),
+                column: 0,
+                row: 0,
+                modifiers: KeyModifiers::empty(),
+                mouse_modifiers: MouseModifiers::MultipleClick(1),
+            });
+        }
+
+        let mut tokens: Vec<_> = s.split('-').collect();
+        let button = match tokens.pop().ok_or_else(|| anyhow!("Missing mouse button"))? {
+            "left" => MouseButton::Left,
+            "right" => MouseButton::Right,
+            "middle" => MouseButton::Middle,
+            invalid => return Err(anyhow!("Invalid mouse button '{}'", invalid)),
+        };
+
+        let count: u8 = match tokens.pop() {
+            Some(count) => count
+                .parse()
+                .map_err(|_| anyhow!("Invalid click count '{}'", count))?,
+            None => return Err(anyhow!("Missing click count, e.g. '1-left'")),
+        };
+
+        let mut modifiers = KeyModifiers::empty();
+        for token in tokens {
+            let flag = match token {
+                "S" => KeyModifiers::SHIFT,
+                "A" => KeyModifiers::ALT,
+                "C" => KeyModifiers::CONTROL,
+                _ => return Err(anyhow!("Invalid key modifier '{}-'", token)),
+            };
+
+            if modifiers.contains(flag) {
+                return Err(anyhow!("Repeated key modifier '{}-'", token));
+            }
+            modifiers.insert(flag);
         }
+
+        Ok(MouseEvent {
+            kind: MouseEventKind::Down(button),
+            column: 0,
+            row: 0,
+            modifiers,
+            mouse_modifiers: MouseModifiers::MultipleClick(count),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for MouseEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
     }
 }
 
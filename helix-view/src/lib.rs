@@ -11,6 +11,7 @@ pub mod handlers;
 pub mod info;
 pub mod register;
 pub mod tree;
+pub mod trust;
 pub mod view;
 
 pub use helix_input::clipboard;
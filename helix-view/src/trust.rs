@@ -0,0 +1,179 @@
+//! Per-workspace, per-capability trust decisions.
+//!
+//! Opening a workspace can implicitly run a language server, execute shell commands
+//! (`:sh`, `:pipe`, `:pipe-to`), and load a local `.helix/config.toml` — all of which a
+//! malicious repository could abuse. Trust is recorded per capability (not all-or-nothing)
+//! and keyed by the canonicalized workspace path *and* a content hash of the local config
+//! file, so editing that file invalidates the decision and requires re-confirming trust.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+const FILE_NAME: &str = "trust.json";
+
+bitflags::bitflags! {
+    /// Capabilities that a workspace may be trusted (or not trusted) to use.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct TrustCapability: u8 {
+        /// Starting language servers for documents that belong to this workspace.
+        const LSP = 0b001;
+        /// Running shell commands (`:sh`, `:pipe`, `:pipe-to`, and their keybindings).
+        const SHELL = 0b010;
+        /// Loading the workspace's local `.helix/config.toml`.
+        const CONFIG = 0b100;
+    }
+}
+
+/// The outcome of a trust prompt: `None` if the user cancelled without deciding anything,
+/// `Some(capabilities)` otherwise (an empty set means "trust nothing").
+pub type TrustDecision = Option<TrustCapability>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TrustEntry {
+    capabilities: u8,
+    config_hash: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TrustStore {
+    workspaces: HashMap<String, TrustEntry>,
+}
+
+impl TrustStore {
+    pub fn load() -> Self {
+        let path = helix_loader::cache_dir().join(FILE_NAME);
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+                log::warn!("Failed to parse trust store from {}: {}", path.display(), err);
+                Self::default()
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(err) => {
+                log::warn!("Failed to read trust store from {}: {}", path.display(), err);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let path = helix_loader::cache_dir().join(FILE_NAME);
+        if let Some(parent) = path.parent() {
+            if let Err(err) = std::fs::create_dir_all(parent) {
+                log::warn!(
+                    "Failed to create cache directory {}: {}",
+                    parent.display(),
+                    err
+                );
+                return;
+            }
+        }
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => {
+                if let Err(err) = std::fs::write(&path, contents) {
+                    log::warn!("Failed to write trust store to {}: {}", path.display(), err);
+                }
+            }
+            Err(err) => log::warn!("Failed to serialize trust store: {}", err),
+        }
+    }
+
+    /// Returns the trusted capabilities for `workspace`, or `None` if there is no recorded
+    /// decision, or the decision was recorded against a different `config_hash` (the local
+    /// config file changed since the user last decided, so the decision no longer applies).
+    pub fn capabilities(&self, workspace: &Path, config_hash: &str) -> Option<TrustCapability> {
+        let entry = self.workspaces.get(&workspace_key(workspace))?;
+        if entry.config_hash != config_hash {
+            return None;
+        }
+        TrustCapability::from_bits(entry.capabilities)
+    }
+
+    pub fn set(&mut self, workspace: &Path, config_hash: String, capabilities: TrustCapability) {
+        self.workspaces.insert(
+            workspace_key(workspace),
+            TrustEntry {
+                capabilities: capabilities.bits(),
+                config_hash,
+            },
+        );
+    }
+}
+
+fn workspace_key(workspace: &Path) -> String {
+    helix_stdx::path::canonicalize(workspace)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Path to the local config file a workspace's trust decision is hashed against.
+pub fn workspace_config_path(workspace: &Path) -> PathBuf {
+    workspace.join(".helix").join("config.toml")
+}
+
+/// Content hash of `path`, used to invalidate trust decisions whenever the local config
+/// file is edited. Missing files hash to a stable, distinct value so a workspace without a
+/// local config still gets a deterministic (and different) hash than one that has one.
+pub fn config_hash(path: &Path) -> String {
+    match std::fs::read(path) {
+        Ok(bytes) => sha1_smol::Sha1::from(&bytes).digest().to_string(),
+        Err(_) => "no-local-config".to_string(),
+    }
+}
+
+/// Whether `workspace` is currently trusted to use `capability`, according to the decision
+/// recorded for its *current* local config contents.
+pub fn is_allowed(workspace: &Path, capability: TrustCapability) -> bool {
+    let hash = config_hash(&workspace_config_path(workspace));
+    TrustStore::load()
+        .capabilities(workspace, &hash)
+        .is_some_and(|granted| granted.contains(capability))
+}
+
+/// Records `capabilities` as trusted for `workspace`'s current local config contents.
+pub fn trust(workspace: &Path, capabilities: TrustCapability) {
+    let hash = config_hash(&workspace_config_path(workspace));
+    let mut store = TrustStore::load();
+    store.set(workspace, hash, capabilities);
+    store.save();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capabilities_require_matching_config_hash() {
+        let mut store = TrustStore::default();
+        let workspace = Path::new("/tmp/some-workspace");
+        store.set(
+            workspace,
+            "abc".to_string(),
+            TrustCapability::LSP | TrustCapability::SHELL,
+        );
+
+        assert_eq!(
+            store.capabilities(workspace, "abc"),
+            Some(TrustCapability::LSP | TrustCapability::SHELL)
+        );
+        assert_eq!(store.capabilities(workspace, "different-hash"), None);
+    }
+
+    #[test]
+    fn roundtrip_serialization() {
+        let mut store = TrustStore::default();
+        store.set(
+            Path::new("/tmp/a"),
+            "hash".to_string(),
+            TrustCapability::CONFIG,
+        );
+
+        let json = serde_json::to_string(&store).unwrap();
+        let deserialized: TrustStore = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            deserialized.capabilities(Path::new("/tmp/a"), "hash"),
+            Some(TrustCapability::CONFIG)
+        );
+    }
+}